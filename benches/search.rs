@@ -1,7 +1,9 @@
 //! Index benchmarks
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use interpolation_search::{InterpolationFactor, InterpolationSearch};
+use interpolation_search::{
+    set_ops, InterpolationFactor, InterpolationSearch, InterpolationSearchTree,
+};
 use rand::{distr::Uniform, rngs::StdRng, Rng, SeedableRng};
 
 fn bench_search(c: &mut Criterion) {
@@ -37,8 +39,92 @@ fn bench_search_type<T: Ord + InterpolationFactor>(
     }
 }
 
-fn create_sample<T: Ord>(count: usize, mut mapper: impl FnMut(u32) -> T) -> (Vec<T>, T) {
-    let mut rng = StdRng::seed_from_u64(5);
+fn bench_skewed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Vec<u64>, skewed");
+
+    for i in (1..5).map(|n| 100_usize.pow(n)) {
+        // Cubes of keys below 2 million fit in a `u64`.
+        let (vec, target) = create_sample(i, |x| u64::from(x % 2_000_000).pow(3));
+        let tree = InterpolationSearchTree::new(vec.clone());
+        let _ = group.bench_function(BenchmarkId::new("binary_search", i), |b| {
+            b.iter(|| {
+                _ = vec.binary_search(&target);
+            });
+        });
+        let _ = group.bench_function(BenchmarkId::new("interpolation_search", i), |b| {
+            b.iter(|| {
+                _ = vec.interpolation_search(&target);
+            });
+        });
+        let _ = group.bench_function(BenchmarkId::new("interpolation_search_tree", i), |b| {
+            b.iter(|| {
+                _ = tree.get(&target);
+            });
+        });
+    }
+}
+
+fn bench_set_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("set operations, Vec<u32>");
+
+    // The inputs are drawn with different seeds, otherwise `small` would be a prefix of `large`.
+    let (small, _) = create_seeded_sample(100, 7, |x| x);
+    for i in (2..5).map(|n| 100_usize.pow(n)) {
+        let (large, _) = create_seeded_sample(i, 5, |x| x);
+        let _ = group.bench_function(BenchmarkId::new("naive intersection", i), |b| {
+            b.iter(|| merge_intersection(&small, &large).count());
+        });
+        let _ = group.bench_function(BenchmarkId::new("intersection", i), |b| {
+            b.iter(|| set_ops::intersection(&small, &large).count());
+        });
+        let _ = group.bench_function(BenchmarkId::new("naive difference", i), |b| {
+            b.iter(|| merge_difference(&small, &large).count());
+        });
+        let _ = group.bench_function(BenchmarkId::new("difference", i), |b| {
+            b.iter(|| set_ops::difference(&small, &large).count());
+        });
+    }
+}
+
+// The textbook merge, which walks both slices element by element.
+fn merge_intersection<'a, T: Ord>(mut a: &'a [T], mut b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    std::iter::from_fn(move || loop {
+        let (x, y) = (a.first()?, b.first()?);
+        match x.cmp(y) {
+            std::cmp::Ordering::Less => a = &a[1..],
+            std::cmp::Ordering::Greater => b = &b[1..],
+            std::cmp::Ordering::Equal => {
+                (a, b) = (&a[1..], &b[1..]);
+                return Some(x);
+            }
+        }
+    })
+}
+
+fn merge_difference<'a, T: Ord>(mut a: &'a [T], mut b: &'a [T]) -> impl Iterator<Item = &'a T> {
+    std::iter::from_fn(move || loop {
+        let x = a.first()?;
+        match b.first().map(|y| x.cmp(y)) {
+            Some(std::cmp::Ordering::Greater) => b = &b[1..],
+            Some(std::cmp::Ordering::Equal) => (a, b) = (&a[1..], &b[1..]),
+            _ => {
+                a = &a[1..];
+                return Some(x);
+            }
+        }
+    })
+}
+
+fn create_sample<T: Ord>(count: usize, mapper: impl FnMut(u32) -> T) -> (Vec<T>, T) {
+    create_seeded_sample(count, 5, mapper)
+}
+
+fn create_seeded_sample<T: Ord>(
+    count: usize,
+    seed: u64,
+    mut mapper: impl FnMut(u32) -> T,
+) -> (Vec<T>, T) {
+    let mut rng = StdRng::seed_from_u64(seed);
 
     let uniform = Uniform::try_from(0..100_000_000).unwrap();
     let target = mapper(rng.sample(uniform));
@@ -102,5 +188,5 @@ impl InterpolationFactor for ExpensiveOrdAndFactor {
         ExpensiveFactor(self.0).interpolation_factor(&ExpensiveFactor(a.0), &ExpensiveFactor(b.0))
     }
 }
-criterion_group!(benches, bench_search);
+criterion_group!(benches, bench_search, bench_skewed, bench_set_ops);
 criterion_main!(benches);
@@ -0,0 +1,192 @@
+use crate::{combinators::from_ref, interpolation_factor::first_difference, InterpolationFactor};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Orders and interpolates the wrapped string case-insensitively, by the lowercase mappings of its
+/// characters.
+///
+/// The comparison is lazy, so sorted lists can be searched without allocating lowercase copies.
+/// Strings that only differ in case are equal. Lowercasing is done character by character as in
+/// [`char::to_lowercase`], which doesn't cover full case folding: "Straße" and "STRASSE" are not
+/// equal.
+///
+/// The wrapper is unsized for `str`, so a borrowed string can be turned into a key with
+/// [`from_ref`](CaseInsensitive::from_ref).
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{CaseInsensitive, InterpolationSearch};
+///
+/// let mut tags = vec!["Rust", "async", "Zig", "cargo", "ÉCOLE", "éclair"];
+/// tags.sort_by(|a, b| CaseInsensitive(a).cmp(&CaseInsensitive(b)));
+/// assert_eq!(tags, ["async", "cargo", "Rust", "Zig", "éclair", "ÉCOLE"]);
+///
+/// let target = CaseInsensitive::from_ref("École");
+/// let idx = tags.interpolation_search_by_key(target, |tag| CaseInsensitive::from_ref(*tag));
+/// assert_eq!(idx, Ok(5));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct CaseInsensitive<S: ?Sized>(pub S);
+
+/// Orders and interpolates the wrapped string case-insensitively for ASCII letters only.
+///
+/// This is cheaper than [`CaseInsensitive`]. The strings are ordered by comparing the
+/// [`to_ascii_lowercase`](u8::to_ascii_lowercase) of each byte, so non-ASCII characters are
+/// compared as they are, and equality agrees with [`str::eq_ignore_ascii_case`].
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{AsciiCaseInsensitive, InterpolationSearch};
+///
+/// let hosts = ["api.Example.com", "CDN.example.com", "www.example.COM"];
+/// let target = AsciiCaseInsensitive::from_ref("cdn.EXAMPLE.com");
+/// let idx = hosts.interpolation_search_by_key(target, |host| AsciiCaseInsensitive::from_ref(*host));
+/// assert_eq!(idx, Ok(1));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct AsciiCaseInsensitive<S: ?Sized>(pub S);
+
+fn lowercase(s: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    s.chars().flat_map(char::to_lowercase)
+}
+
+fn ascii_lowercase(s: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    s.chars().map(|c| c.to_ascii_lowercase())
+}
+
+macro_rules! case_insensitive {
+    ($t:ident, $fold:ident) => {
+        from_ref!($t<S: ?Sized>);
+
+        impl<S: AsRef<str> + ?Sized> PartialEq for $t<S> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl<S: AsRef<str> + ?Sized> Eq for $t<S> {}
+
+        impl<S: AsRef<str> + ?Sized> PartialOrd for $t<S> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<S: AsRef<str> + ?Sized> Ord for $t<S> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                $fold(self.0.as_ref()).cmp($fold(other.0.as_ref()))
+            }
+        }
+
+        // Strings that only differ in case must hash alike, so the folded characters are hashed,
+        // followed by their count to keep the encoding prefix-free.
+        impl<S: AsRef<str> + ?Sized> Hash for $t<S> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let mut len = 0;
+                for c in $fold(self.0.as_ref()) {
+                    c.hash(state);
+                    len += 1;
+                }
+                state.write_usize(len);
+            }
+        }
+
+        impl<S: AsRef<str> + ?Sized> InterpolationFactor for $t<S> {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                let (mid, a, b) = (self.0.as_ref(), a.0.as_ref(), b.0.as_ref());
+                match first_difference($fold(mid), $fold(a), $fold(b)) {
+                    Some((mid, a, b)) => mid.interpolation_factor(&a, &b),
+                    None => 0.5,
+                }
+            }
+        }
+    };
+}
+
+case_insensitive!(CaseInsensitive, lowercase);
+case_insensitive!(AsciiCaseInsensitive, ascii_lowercase);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterpolationSearch;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn test_ordering() {
+        assert_eq!(CaseInsensitive("Hello"), CaseInsensitive("hELLO"));
+        assert!(CaseInsensitive("apple") < CaseInsensitive("Banana"));
+        assert!(CaseInsensitive("Zebra") > CaseInsensitive("apple"));
+        assert!(CaseInsensitive("ab") < CaseInsensitive("ABC"));
+        assert_eq!(CaseInsensitive("ÄRGER"), CaseInsensitive("ärger"));
+
+        assert_eq!(AsciiCaseInsensitive("Hello"), AsciiCaseInsensitive("hELLO"));
+        assert_ne!(AsciiCaseInsensitive("ÄRGER"), AsciiCaseInsensitive("ärger"));
+        assert!(AsciiCaseInsensitive("apple") < AsciiCaseInsensitive("Banana"));
+    }
+
+    #[test]
+    fn test_hash() {
+        let state = RandomState::new();
+        assert_eq!(
+            state.hash_one(CaseInsensitive("Straße")),
+            state.hash_one(CaseInsensitive("STRAßE"))
+        );
+        assert_eq!(
+            state.hash_one(AsciiCaseInsensitive(String::from("Cargo"))),
+            state.hash_one(AsciiCaseInsensitive(String::from("CARGO")))
+        );
+    }
+
+    #[test]
+    fn test_interpolation_factor() {
+        let (a, b) = (CaseInsensitive("Aa"), CaseInsensitive("aZ"));
+        assert_eq!(CaseInsensitive("AA").interpolation_factor(&a, &b), 0.0);
+        assert_eq!(CaseInsensitive("az").interpolation_factor(&a, &b), 1.0);
+        assert_eq!(
+            CaseInsensitive("aM").interpolation_factor(&a, &b),
+            'm'.interpolation_factor(&'a', &'z')
+        );
+        let (a, b) = (AsciiCaseInsensitive("A"), AsciiCaseInsensitive("z"));
+        assert_eq!(AsciiCaseInsensitive("Z").interpolation_factor(&a, &b), 1.0);
+    }
+
+    #[test]
+    fn test_search_without_allocating() {
+        let mut names = (0..2000_u32)
+            .map(|n| {
+                let name = format!("user{:05}", n.wrapping_mul(7919) % 100_000);
+                if n % 3 == 0 {
+                    name.to_uppercase()
+                } else {
+                    name
+                }
+            })
+            .collect::<Vec<_>>();
+        names.sort_by_key(|name| name.to_lowercase());
+        for (idx, name) in names.iter().enumerate() {
+            let target = name.to_ascii_uppercase();
+            let found = names
+                .interpolation_search_by_key(CaseInsensitive::from_ref(target.as_str()), |name| {
+                    CaseInsensitive::from_ref(name.as_str())
+                });
+            assert_eq!(found, Ok(idx));
+            let found = names.interpolation_search_by_key(
+                AsciiCaseInsensitive::from_ref(target.as_str()),
+                |name| AsciiCaseInsensitive::from_ref(name.as_str()),
+            );
+            assert_eq!(found, Ok(idx));
+        }
+        let found = names
+            .interpolation_search_by_key(CaseInsensitive::from_ref("USER00000X"), |name| {
+                CaseInsensitive::from_ref(name.as_str())
+            });
+        assert_eq!(found, Err(1));
+    }
+}
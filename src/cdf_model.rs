@@ -0,0 +1,223 @@
+use crate::{InterpolationFactor, InterpolationSearch};
+
+/// A piecewise-linear model of the cumulative distribution of the keys of a sorted slice.
+///
+/// Plain interpolation search assumes that the keys are spread linearly between the ends of the
+/// slice. The model instead samples every `step`-th key once, and uses the samples to narrow the
+/// search down to a single segment of the slice, in which the keys are much closer to uniform even
+/// for skewed distributions. The search then continues with [`InterpolationFactor`] inside that
+/// segment, so its first probe is the position predicted by the model.
+///
+/// The model doesn't borrow the slice it was built from. The search methods must be given the
+/// same, unmodified slice, otherwise the returned result is unspecified and meaningless.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::CdfModel;
+///
+/// let arr = (0..1000_u64).map(|n| n * n * n).collect::<Vec<_>>();
+/// let model = CdfModel::new(&arr, 16);
+///
+/// assert_eq!(model.search(&arr, &(500 * 500 * 500)), Ok(500));
+/// assert_eq!(model.search(&arr, &7), Err(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdfModel<K> {
+    keys: Vec<K>,
+    positions: Vec<usize>,
+    len: usize,
+}
+
+impl<K> CdfModel<K>
+where
+    K: Ord + InterpolationFactor + Clone,
+{
+    /// Builds the model by sampling every `step`-th element of the sorted `slice`, as well as its
+    /// last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(slice: &[K], step: usize) -> Self {
+        Self::new_by_key(slice, step, |x| x)
+    }
+
+    /// Builds the model by sampling the keys of every `step`-th element of `slice`, sorted by the
+    /// key extraction function, as well as of its last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new_by_key<T, F>(slice: &[T], step: usize, mut key: F) -> Self
+    where
+        F: FnMut(&T) -> &K,
+    {
+        assert!(step > 0, "the sampling step must be positive");
+        let mut positions = (0..slice.len()).step_by(step).collect::<Vec<_>>();
+        if positions
+            .last()
+            .is_some_and(|&last| last + 1 != slice.len())
+        {
+            positions.push(slice.len() - 1);
+        }
+        let keys = positions
+            .iter()
+            .map(|&idx| key(&slice[idx]).clone())
+            .collect();
+        Self {
+            keys,
+            positions,
+            len: slice.len(),
+        }
+    }
+
+    /// Returns the number of sampled keys the model consists of.
+    pub fn sample_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the position of `target` in the modeled slice, as estimated by the piecewise-linear
+    /// distribution. The estimate is always in `0..=len`.
+    pub fn predict(&self, target: &K) -> usize {
+        let ub = self.keys.partition_point(|key| key < target);
+        match (ub.checked_sub(1), self.keys.get(ub)) {
+            (None, _) => 0,
+            (Some(_), None) => self.len,
+            (Some(lb), Some(upper)) => {
+                let (first, last) = (self.positions[lb], self.positions[ub]);
+                let f = target.interpolation_factor(&self.keys[lb], upper);
+                first + ((last - first) as f32 * f.clamp(0.0, 1.0)) as usize
+            }
+        }
+    }
+
+    /// Searches `slice`, the one the model was built from, for `target`.
+    ///
+    /// The result is the same as that of [`InterpolationSearch::interpolation_search`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slice` differs from the one of the modeled slice.
+    pub fn search(&self, slice: &[K], target: &K) -> Result<usize, usize> {
+        self.search_by_key(slice, target, |x| x)
+    }
+
+    /// Searches `slice`, the one the model was built from, with a key extraction function.
+    ///
+    /// The result is the same as that of [`InterpolationSearch::interpolation_search_by_key`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `slice` differs from the one of the modeled slice.
+    pub fn search_by_key<T, F>(&self, slice: &[T], target: &K, key: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> &K,
+    {
+        assert_eq!(
+            slice.len(),
+            self.len,
+            "the model was built from a slice of another length"
+        );
+        // Every element before `first` is less than `target`, every element from `last` on is
+        // greater than or equal to it.
+        let ub = self.keys.partition_point(|k| k < target);
+        let first = ub.checked_sub(1).map_or(0, |lb| self.positions[lb]);
+        let last = self.positions.get(ub).map_or(slice.len(), |&idx| idx + 1);
+        match slice[first..last].interpolation_search_by_key(target, key) {
+            Ok(idx) => Ok(first + idx),
+            Err(idx) => Err(first + idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let arr: [u32; 0] = [];
+        let model = CdfModel::new(&arr, 4);
+        assert_eq!(model.sample_count(), 0);
+        assert_eq!(model.predict(&5), 0);
+        assert_eq!(model.search(&arr, &5), Err(0));
+    }
+
+    #[test]
+    fn test_sampling() {
+        let arr = (0..10).collect::<Vec<u32>>();
+        assert_eq!(CdfModel::new(&arr, 1).sample_count(), 10);
+        assert_eq!(CdfModel::new(&arr, 3).positions, [0, 3, 6, 9]);
+        assert_eq!(CdfModel::new(&arr, 4).positions, [0, 4, 8, 9]);
+        assert_eq!(CdfModel::new(&arr, 100).positions, [0, 9]);
+        assert_eq!(CdfModel::new(&arr[..1], 100).positions, [0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_step() {
+        CdfModel::new(&[1, 2, 3], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "another length")]
+    fn test_other_slice() {
+        let arr = (0..100).collect::<Vec<u32>>();
+        let model = CdfModel::new(&arr, 8);
+        let _ = model.search(&arr[..50], &75);
+    }
+
+    #[test]
+    fn test_predict() {
+        // Exponentially growing keys: the model is exact at the samples and close in between.
+        let arr = (0..32).map(|n| 1_u64 << n).collect::<Vec<_>>();
+        let model = CdfModel::new(&arr, 4);
+        for (idx, key) in arr.iter().enumerate() {
+            assert!(model.predict(key).abs_diff(idx) < 4);
+        }
+        assert_eq!(model.predict(&0), 0);
+        assert_eq!(model.predict(&u64::MAX), 32);
+    }
+
+    #[test]
+    fn test_against_binary_search() {
+        let mut arr = (0..2000_u64)
+            .map(|n| n * n * n / 1000 + n % 7)
+            .collect::<Vec<_>>();
+        arr.sort();
+        for step in [1, 2, 7, 64, 5000] {
+            let model = CdfModel::new(&arr, step);
+            for target in (0..arr[arr.len() - 1] + 10).step_by(997) {
+                match model.search(&arr, &target) {
+                    Ok(idx) => assert_eq!(arr[idx], target),
+                    Err(idx) => assert_eq!(Err(idx), arr.binary_search(&target)),
+                }
+            }
+            for (idx, target) in arr.iter().enumerate() {
+                assert!(model
+                    .search(&arr, target)
+                    .is_ok_and(|found| arr[found] == arr[idx]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_by_key() {
+        let data = [
+            (1, 1),
+            (2, 10),
+            (3, 100),
+            (4, 1000),
+            (5, 10_000),
+            (6, 100_000),
+        ];
+        let model = CdfModel::new_by_key(&data, 2, |pair| &pair.1);
+        assert_eq!(model.sample_count(), 4);
+        assert_eq!(model.search_by_key(&data, &1000, |pair| &pair.1), Ok(3));
+        assert_eq!(model.search_by_key(&data, &0, |pair| &pair.1), Err(0));
+        assert_eq!(model.search_by_key(&data, &500, |pair| &pair.1), Err(3));
+        assert_eq!(model.search_by_key(&data, &100_000, |pair| &pair.1), Ok(5));
+        assert_eq!(model.search_by_key(&data, &100_001, |pair| &pair.1), Err(6));
+    }
+}
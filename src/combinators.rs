@@ -0,0 +1,279 @@
+use crate::InterpolationFactor;
+use std::{cmp::Ordering, fmt, time::Duration};
+
+/// Order-preserving conversion of a value into `f64`, used by the [`LogScale`] and [`Sqrt`]
+/// combinators to transform the value before interpolating.
+///
+/// For `a <= b`, `a.to_f64() <= b.to_f64()` must hold.
+pub trait Numeric {
+    /// Returns the value as `f64`, possibly rounded.
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! numeric {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+numeric!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Numeric for Duration {
+    fn to_f64(&self) -> f64 {
+        self.as_secs_f64()
+    }
+}
+
+/// Interpolates the logarithm of the wrapped value rather than the value itself.
+///
+/// Straightens exponentially or log-normally distributed keys, which are the worst case for plain
+/// interpolation. The ordering is the one of the wrapped value. Zero and negative values are
+/// supported through the symmetric `sign(x) * ln(1 + |x|)` transformation.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{InterpolationSearch, LogScale};
+///
+/// let arr = (0..64).map(|n| LogScale(1_u64 << n)).collect::<Vec<_>>();
+/// assert_eq!(arr.interpolation_search(&LogScale(1 << 40)), Ok(40));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct LogScale<T>(pub T);
+
+/// Interpolates the square root of the wrapped value rather than the value itself.
+///
+/// Suits keys that grow quadratically. The ordering is the one of the wrapped value. Negative
+/// values are supported through the symmetric `sign(x) * sqrt(|x|)` transformation.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{InterpolationSearch, Sqrt};
+///
+/// let arr = (0..1000_u64).map(|n| Sqrt(n * n)).collect::<Vec<_>>();
+/// assert_eq!(arr.interpolation_search(&Sqrt(500 * 500)), Ok(500));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Sqrt<T>(pub T);
+
+// Implements `from_ref` for a wrapper type over its only type parameter. This is the only unsafe
+// code of the crate, and the wrapper must be `repr(transparent)` for it to be sound.
+macro_rules! from_ref {
+    ($t:ident<$p:ident $(: ?$sized:ident)?>) => {
+        impl<$p $(: ?$sized)?> $t<$p> {
+            /// Converts a reference to the wrapped value into a reference to the wrapper, e.g. to
+            /// return it from the key extraction function of `interpolation_search_by_key`.
+            pub fn from_ref(value: &$p) -> &Self {
+                // SAFETY: `Self` is `repr(transparent)` over the parameter.
+                unsafe { &*(value as *const $p as *const Self) }
+            }
+        }
+    };
+}
+
+pub(crate) use from_ref;
+
+macro_rules! transparent_wrapper {
+    ($t:ident, $transform:expr) => {
+        from_ref!($t<T>);
+
+        impl<T: Numeric> InterpolationFactor for $t<T> {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                let transform: fn(f64) -> f64 = $transform;
+                f64_factor(
+                    transform(self.0.to_f64()),
+                    transform(a.0.to_f64()),
+                    transform(b.0.to_f64()),
+                )
+            }
+        }
+    };
+}
+
+transparent_wrapper!(LogScale, |x| x.signum() * x.abs().ln_1p());
+transparent_wrapper!(Sqrt, |x| x.signum() * x.abs().sqrt());
+
+/// Interpolates the wrapped value through a user-provided monotonic mapping into `f64`.
+///
+/// The ordering is the one of the wrapped value, so `map` must be non-decreasing for the factor to
+/// be consistent with it. Every value of a slice carries its own copy of `map`, which is zero-sized
+/// for closures that don't capture anything. The one of `self` is used when interpolating.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{InterpolationSearch, MapFactor};
+///
+/// let cube_root = |x: &u64| (*x as f64).cbrt();
+/// let arr = (0..100_u64)
+///     .map(|n| MapFactor::new(n * n * n, cube_root))
+///     .collect::<Vec<_>>();
+/// assert_eq!(arr.interpolation_search(&MapFactor::new(27_000, cube_root)), Ok(30));
+/// ```
+#[derive(Clone, Copy)]
+pub struct MapFactor<T, F> {
+    value: T,
+    map: F,
+}
+
+impl<T, F: Fn(&T) -> f64> MapFactor<T, F> {
+    /// Wraps `value` with the monotonic `map` to be interpolated through.
+    pub fn new(value: T, map: F) -> Self {
+        Self { value, map }
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for MapFactor<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MapFactor").field(&self.value).finish()
+    }
+}
+
+impl<T: PartialEq, F> PartialEq for MapFactor<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, F> Eq for MapFactor<T, F> {}
+
+impl<T: PartialOrd, F> PartialOrd for MapFactor<T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, F> Ord for MapFactor<T, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T, F: Fn(&T) -> f64> InterpolationFactor for MapFactor<T, F> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        f64_factor(
+            (self.map)(&self.value),
+            (self.map)(&a.value),
+            (self.map)(&b.value),
+        )
+    }
+}
+
+pub(crate) fn f64_factor(mid: f64, a: f64, b: f64) -> f32 {
+    let f = (mid - a) / (b - a);
+    if a == b || f.is_nan() {
+        0.5
+    } else {
+        f.clamp(0.0, 1.0) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterpolationSearch;
+
+    #[test]
+    fn test_log_scale() {
+        let f = LogScale(99).interpolation_factor(&LogScale(0), &LogScale(9999));
+        assert!((f - 0.5).abs() < 1e-3);
+        assert_eq!(
+            LogScale(0).interpolation_factor(&LogScale(0), &LogScale(10)),
+            0.0
+        );
+        assert_eq!(
+            LogScale(10).interpolation_factor(&LogScale(0), &LogScale(10)),
+            1.0
+        );
+        assert_eq!(
+            LogScale(5).interpolation_factor(&LogScale(5), &LogScale(5)),
+            0.5
+        );
+        assert_eq!(
+            LogScale(0).interpolation_factor(&LogScale(-10), &LogScale(10)),
+            0.5
+        );
+        assert_eq!(
+            LogScale(20).interpolation_factor(&LogScale(-10), &LogScale(10)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Sqrt(25).interpolation_factor(&Sqrt(0), &Sqrt(100)), 0.5);
+        assert_eq!(Sqrt(0).interpolation_factor(&Sqrt(0), &Sqrt(100)), 0.0);
+        assert_eq!(Sqrt(100).interpolation_factor(&Sqrt(0), &Sqrt(100)), 1.0);
+        assert_eq!(Sqrt(-25).interpolation_factor(&Sqrt(-100), &Sqrt(0)), 0.5);
+        assert_eq!(Sqrt(5).interpolation_factor(&Sqrt(5), &Sqrt(5)), 0.5);
+    }
+
+    #[test]
+    fn test_map_factor() {
+        let log2 = |x: &u32| f64::from(*x).log2();
+        let f = |x| MapFactor::new(x, log2);
+        assert_eq!(f(16).interpolation_factor(&f(4), &f(64)), 0.5);
+        assert_eq!(f(4).interpolation_factor(&f(4), &f(64)), 0.0);
+        assert_eq!(f(64).interpolation_factor(&f(4), &f(64)), 1.0);
+        assert_eq!(f(4).interpolation_factor(&f(4), &f(4)), 0.5);
+        assert!(f(3) < f(4));
+        assert_eq!(f(3).into_inner(), 3);
+    }
+
+    #[test]
+    fn test_skewed_search() {
+        let mut values = (0..40).map(|n| 1.5_f64.powi(n) as u64).collect::<Vec<_>>();
+        values.dedup();
+        let ln = |x: &u64| (*x as f64).ln_1p();
+
+        let log = values.iter().copied().map(LogScale).collect::<Vec<_>>();
+        let sqrt = values.iter().copied().map(Sqrt).collect::<Vec<_>>();
+        let mapped = values
+            .iter()
+            .map(|&n| MapFactor::new(n, ln))
+            .collect::<Vec<_>>();
+        for n in values.iter().flat_map(|&n| [n.saturating_sub(1), n, n + 1]) {
+            let expected = values.binary_search(&n);
+            assert_eq!(log.interpolation_search(&LogScale(n)), expected);
+            assert_eq!(sqrt.interpolation_search(&Sqrt(n)), expected);
+            assert_eq!(
+                mapped.interpolation_search(&MapFactor::new(n, ln)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let data = [(1, 10_u64), (2, 100), (3, 1000), (4, 10_000)];
+        assert_eq!(
+            data.interpolation_search_by_key(LogScale::from_ref(&1000), |pair| {
+                LogScale::from_ref(&pair.1)
+            }),
+            Ok(2)
+        );
+        assert_eq!(
+            data.interpolation_search_by_key(Sqrt::from_ref(&50), |pair| Sqrt::from_ref(&pair.1)),
+            Err(1)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    net::{Ipv4Addr, Ipv6Addr},
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Extends types with a `distance` method to measure how far apart two values are.
+///
+/// The distance is used by [`InterpolationSearch::interpolation_nearest`] to pick the closer of the
+/// neighbours of a target. It must be consistent with [`Ord`]: for `a <= b <= c`, `b` must not be
+/// farther from `a` than `c` is.
+///
+/// This crate implements the trait for integer types, `char`, IP addresses and time types.
+///
+/// [`InterpolationSearch::interpolation_nearest`]: crate::InterpolationSearch::interpolation_nearest
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::Distance;
+/// use std::time::Duration;
+///
+/// assert_eq!((-3_i8).distance(&5), 8_u8);
+/// assert_eq!('a'.distance(&'d'), 3);
+/// assert_eq!(
+///     Duration::from_secs(2).distance(&Duration::from_secs(5)),
+///     Duration::from_secs(3)
+/// );
+/// ```
+pub trait Distance {
+    /// The type of the distance, such as the unsigned counterpart of an integer type.
+    type Output: Ord;
+
+    /// Returns the distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> Self::Output;
+}
+
+macro_rules! integer_distance {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl Distance for $t {
+                type Output = $u;
+
+                fn distance(&self, other: &Self) -> $u {
+                    self.abs_diff(*other)
+                }
+            }
+        )*
+    };
+}
+
+integer_distance!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+impl Distance for char {
+    type Output = u32;
+
+    fn distance(&self, other: &Self) -> u32 {
+        u32::from(*self).abs_diff(u32::from(*other))
+    }
+}
+
+impl Distance for Ipv4Addr {
+    type Output = u32;
+
+    fn distance(&self, other: &Self) -> u32 {
+        self.to_bits().abs_diff(other.to_bits())
+    }
+}
+
+impl Distance for Ipv6Addr {
+    type Output = u128;
+
+    fn distance(&self, other: &Self) -> u128 {
+        self.to_bits().abs_diff(other.to_bits())
+    }
+}
+
+impl Distance for Duration {
+    type Output = Duration;
+
+    fn distance(&self, other: &Self) -> Duration {
+        self.abs_diff(*other)
+    }
+}
+
+impl Distance for SystemTime {
+    type Output = Duration;
+
+    fn distance(&self, other: &Self) -> Duration {
+        self.duration_since(*other)
+            .unwrap_or_else(|err| err.duration())
+    }
+}
+
+impl Distance for Instant {
+    type Output = Duration;
+
+    fn distance(&self, other: &Self) -> Duration {
+        // `duration_since` saturates to zero if `other` is later.
+        self.duration_since(*other).max(other.duration_since(*self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(3_u8.distance(&200), 197);
+        assert_eq!(i8::MIN.distance(&i8::MAX), u8::MAX);
+        assert_eq!(i128::MIN.distance(&0), 1 << 127);
+        assert_eq!(7_usize.distance(&7), 0);
+    }
+
+    #[test]
+    fn test_ip_addrs() {
+        let a = Ipv4Addr::new(10, 0, 0, 1);
+        let b = Ipv4Addr::new(10, 0, 1, 0);
+        assert_eq!(a.distance(&b), 255);
+        assert_eq!(Ipv6Addr::LOCALHOST.distance(&Ipv6Addr::UNSPECIFIED), 1);
+    }
+
+    #[test]
+    fn test_time() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_millis(1500);
+        assert_eq!(t0.distance(&t1), Duration::from_millis(1500));
+        assert_eq!(t1.distance(&t0), Duration::from_millis(1500));
+
+        let i0 = Instant::now();
+        let i1 = i0 + Duration::from_secs(3);
+        assert_eq!(i0.distance(&i1), Duration::from_secs(3));
+        assert_eq!(i1.distance(&i0), Duration::from_secs(3));
+    }
+}
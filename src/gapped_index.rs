@@ -0,0 +1,493 @@
+use crate::{interpolation_search::upper_bound_by_key, InterpolationFactor};
+
+// Share of the slots of a data node that is occupied right after it is trained.
+const INIT_DENSITY: f64 = 0.7;
+// A data node is retrained once more than this share of its slots is occupied.
+const MAX_DENSITY: f64 = 0.8;
+// A data node is retrained once the search distance and the shifts of its inserts since the last
+// training exceed this many slots per key.
+const MAX_AVG_COST: usize = 8;
+// Data nodes with more keys are split in two when retrained.
+const MAX_NODE_LEN: usize = 1024;
+// Data nodes with fewer keys are never split because of their cost.
+const MIN_SPLIT_LEN: usize = 64;
+
+/// An updatable learned index over a set of keys, in the style of ALEX.
+///
+/// The keys are partitioned into data nodes, each a sorted array with gaps, whose keys are placed
+/// by a model: the position of a key is its [`InterpolationFactor`] between the smallest and the
+/// largest key of the node at training time, scaled to the size of the array. An insert searches
+/// from the predicted position, so it usually lands in a nearby gap, and otherwise shifts the
+/// elements up to the closest gap only. A lookup interpolates the position in the same way, then
+/// runs an exponential search around it.
+///
+/// Once a node becomes too dense, or its inserts had to travel too far from their predicted
+/// positions, the node is retrained over a larger array, or split in two if it grew too large or
+/// its keys are too skewed for a single linear model.
+///
+/// The gaps hold copies of the next key, so keys must be [`Clone`]. Keys are unique, like in a
+/// `BTreeSet`.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::GappedIndex;
+///
+/// let mut index = (0..1000_u64).map(|n| n * 10).collect::<GappedIndex<_>>();
+/// assert!(index.insert(15));
+/// assert!(!index.insert(20));
+///
+/// assert!(index.contains(&15));
+/// assert_eq!(index.get(&990), Some(&990));
+///
+/// assert!(index.remove(&10));
+/// assert_eq!(index.len(), 1000);
+/// assert_eq!(index.iter().take(3).collect::<Vec<_>>(), [&0, &15, &20]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GappedIndex<T> {
+    nodes: Vec<DataNode<T>>,
+    // `pivots[i]` is the smallest key that belongs to node `i + 1`.
+    pivots: Vec<T>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct DataNode<T> {
+    // Sorted keys. A gap holds a copy of the next occupied key, and the gaps after the last
+    // occupied slot a copy of that key, so the slots are searchable as a whole.
+    slots: Vec<T>,
+    occupied: Vec<bool>,
+    len: usize,
+    // The keys the model interpolates between.
+    lo: T,
+    hi: T,
+    // Search distance and shifts of the inserts since the node was trained.
+    cost: usize,
+}
+
+impl<T> GappedIndex<T> {
+    /// Creates an empty index.
+    pub const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            pivots: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of keys in the index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the index has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of data nodes the keys are partitioned into.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns an iterator over the keys of the index, in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter().flat_map(|node| {
+            node.slots
+                .iter()
+                .zip(&node.occupied)
+                .filter_map(|(key, &occupied)| occupied.then_some(key))
+        })
+    }
+
+    /// Removes all keys from the index.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<T> GappedIndex<T>
+where
+    T: Ord + InterpolationFactor + Clone,
+{
+    /// Returns a reference to the key equal to `target`, if it's in the index.
+    pub fn get(&self, target: &T) -> Option<&T> {
+        let node = self.nodes.get(self.node_for(target))?;
+        node.find(target).map(|idx| &node.slots[idx])
+    }
+
+    /// Returns `true` if the index contains `target`.
+    pub fn contains(&self, target: &T) -> bool {
+        self.get(target).is_some()
+    }
+
+    /// Inserts `key` into the index. Returns `false` if the index already contained it.
+    pub fn insert(&mut self, key: T) -> bool {
+        if self.nodes.is_empty() {
+            self.nodes.push(DataNode::train(vec![key]));
+            self.len = 1;
+            return true;
+        }
+        let i = self.node_for(&key);
+        if !self.nodes[i].insert(key) {
+            return false;
+        }
+        self.len += 1;
+        if self.nodes[i].needs_training() {
+            self.retrain(i);
+        }
+        true
+    }
+
+    /// Removes `key` from the index. Returns `false` if the index didn't contain it.
+    pub fn remove(&mut self, key: &T) -> bool {
+        let i = self.node_for(key);
+        if !self.nodes.get_mut(i).is_some_and(|node| node.remove(key)) {
+            return false;
+        }
+        self.len -= 1;
+        if self.nodes[i].len == 0 {
+            self.nodes.remove(i);
+            if !self.pivots.is_empty() {
+                self.pivots.remove(i.saturating_sub(1));
+            }
+        }
+        true
+    }
+
+    // Returns the index of the data node `target` belongs to.
+    fn node_for(&self, target: &T) -> usize {
+        upper_bound_by_key(&self.pivots, target, |pivot| pivot)
+    }
+
+    // Retrains the `i`-th data node, splitting it in two if it's too large or too costly.
+    fn retrain(&mut self, i: usize) {
+        let costly = self.nodes[i].cost > self.nodes[i].len * MAX_AVG_COST;
+        let mut keys = self.nodes[i].keys();
+        if keys.len() > MAX_NODE_LEN || (costly && keys.len() >= 2 * MIN_SPLIT_LEN) {
+            let right = keys.split_off(keys.len() / 2);
+            self.pivots.insert(i, right[0].clone());
+            self.nodes[i] = DataNode::train(keys);
+            self.nodes.insert(i + 1, DataNode::train(right));
+        } else {
+            self.nodes[i] = DataNode::train(keys);
+        }
+    }
+}
+
+impl<T> DataNode<T>
+where
+    T: Ord + InterpolationFactor + Clone,
+{
+    // Builds a node over the sorted, unique and non-empty `keys`, placing every key at its
+    // predicted position or right after the previous one.
+    fn train(keys: Vec<T>) -> Self {
+        let n = keys.len();
+        let cap = ((n as f64 / INIT_DENSITY).ceil() as usize).max(n + 1);
+        let (lo, hi) = (keys[0].clone(), keys[n - 1].clone());
+        let mut node = Self {
+            slots: Vec::with_capacity(cap),
+            occupied: vec![false; cap],
+            len: n,
+            lo,
+            hi,
+            cost: 0,
+        };
+        for (i, key) in keys.into_iter().enumerate() {
+            let pos = node.predict(&key).max(node.slots.len()).min(cap - (n - i));
+            while node.slots.len() < pos {
+                node.slots.push(key.clone());
+            }
+            node.slots.push(key);
+            node.occupied[pos] = true;
+        }
+        let last = node.slots[node.slots.len() - 1].clone();
+        node.slots.resize(cap, last);
+        node
+    }
+
+    fn needs_training(&self) -> bool {
+        self.len as f64 > self.slots.len() as f64 * MAX_DENSITY
+            || self.cost > self.len * MAX_AVG_COST
+    }
+
+    // Returns the occupied keys in sorted order.
+    fn keys(&self) -> Vec<T> {
+        self.slots
+            .iter()
+            .zip(&self.occupied)
+            .filter(|(_, &occupied)| occupied)
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    fn predict(&self, target: &T) -> usize {
+        let f = f64::from(target.interpolation_factor(&self.lo, &self.hi));
+        let f = if f.is_finite() {
+            f.clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (f * (self.occupied.len() - 1) as f64).round() as usize
+    }
+
+    // Returns the index of the first slot that is greater than or equal to `target`, searching
+    // exponentially around `pos`.
+    fn lower_bound(&self, target: &T, pos: usize) -> usize {
+        let slots = &self.slots;
+        let (lo, hi);
+        if slots[pos] < *target {
+            let mut bound = 1;
+            let mut first = pos + 1;
+            while pos + bound < slots.len() && slots[pos + bound] < *target {
+                first = pos + bound + 1;
+                bound *= 2;
+            }
+            (lo, hi) = (first, (pos + bound).min(slots.len()));
+        } else {
+            let mut bound = 1;
+            let mut last = pos;
+            while bound <= pos && slots[pos - bound] >= *target {
+                last = pos - bound;
+                bound *= 2;
+            }
+            (lo, hi) = (pos.checked_sub(bound).map_or(0, |idx| idx + 1), last);
+        }
+        lo + slots[lo..hi].partition_point(|key| key < target)
+    }
+
+    // Returns the index of the occupied slot equal to `target`, if there's any.
+    fn find(&self, target: &T) -> Option<usize> {
+        let idx = self.lower_bound(target, self.predict(target));
+        if self.slots.get(idx) != Some(target) {
+            return None;
+        }
+        (idx..self.slots.len()).find(|&idx| self.occupied[idx])
+    }
+
+    fn insert(&mut self, key: T) -> bool {
+        let pos = self.predict(&key);
+        let idx = self.lower_bound(&key, pos);
+        self.cost += idx.abs_diff(pos);
+        let cap = self.slots.len();
+        if self.slots.get(idx) == Some(&key) {
+            return false;
+        }
+        if idx < cap && !self.occupied[idx] {
+            // The slot before a gap that is greater than the key is occupied and less than it.
+            self.slots[idx] = key;
+            self.occupied[idx] = true;
+        } else if idx == cap && !self.occupied[cap - 1] {
+            // Take the first of the trailing gaps, which now hold copies of the new last key.
+            let first = (0..cap)
+                .rfind(|&idx| self.occupied[idx])
+                .map_or(0, |idx| idx + 1);
+            self.slots[first + 1..].fill(key.clone());
+            self.slots[first] = key;
+            self.occupied[first] = true;
+        } else {
+            // Shift the occupied slots between the key and the closest gap towards the gap.
+            let right = (idx..cap).find(|&idx| !self.occupied[idx]);
+            let left = (0..idx).rfind(|&idx| !self.occupied[idx]);
+            match (left, right) {
+                (Some(left), Some(right)) if idx - left <= right - idx => {
+                    self.shift_left(left, idx, key)
+                }
+                (_, Some(right)) => {
+                    self.slots[idx..=right].rotate_right(1);
+                    self.slots[idx] = key;
+                    self.occupied[right] = true;
+                    self.cost += right - idx;
+                }
+                (Some(left), None) => self.shift_left(left, idx, key),
+                (None, None) => unreachable!("a data node always has a gap"),
+            }
+        }
+        self.len += 1;
+        true
+    }
+
+    // Moves the occupied slots `gap + 1..idx` one slot to the left, and puts `key` before `idx`.
+    fn shift_left(&mut self, gap: usize, idx: usize, key: T) {
+        self.slots[gap..idx].rotate_left(1);
+        self.slots[idx - 1] = key;
+        self.occupied[gap] = true;
+        self.cost += idx - 1 - gap;
+    }
+
+    fn remove(&mut self, key: &T) -> bool {
+        let Some(idx) = self.find(key) else {
+            return false;
+        };
+        self.occupied[idx] = false;
+        self.len -= 1;
+        if self.len == 0 {
+            return true;
+        }
+        // The next slot holds the next key, unless the removed key was the last one.
+        match self.slots.get(idx + 1).filter(|next| *next != key) {
+            Some(next) => {
+                let next = next.clone();
+                let mut first = idx;
+                while first > 0 && !self.occupied[first - 1] {
+                    first -= 1;
+                }
+                self.slots[first..=idx].fill(next);
+            }
+            None => {
+                let last = (0..idx).rfind(|&idx| self.occupied[idx]).unwrap_or(0);
+                let last_key = self.slots[last].clone();
+                self.slots[last + 1..].fill(last_key);
+            }
+        }
+        true
+    }
+}
+
+impl<T> Default for GappedIndex<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for GappedIndex<T>
+where
+    T: Ord + InterpolationFactor + Clone,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for GappedIndex<T>
+where
+    T: Ord + InterpolationFactor + Clone,
+{
+    /// Bulk loads the index, training data nodes of half the maximum size.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut keys = iter.into_iter().collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        let len = keys.len();
+        let nodes = keys
+            .chunks(MAX_NODE_LEN / 2)
+            .map(|chunk| DataNode::train(chunk.to_vec()))
+            .collect::<Vec<_>>();
+        let pivots = nodes.iter().skip(1).map(|node| node.lo.clone()).collect();
+        Self { nodes, pivots, len }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn assert_same_keys(index: &GappedIndex<u64>, expected: &BTreeSet<u64>) {
+        assert_eq!(index.len(), expected.len());
+        assert!(index.iter().eq(expected.iter()));
+        for node in &index.nodes {
+            assert!(node.slots.is_sorted());
+            assert_eq!(node.occupied.iter().filter(|&&o| o).count(), node.len);
+            // Every gap holds the next key, or the last one at the end.
+            let last = node.occupied.iter().rposition(|&o| o).unwrap();
+            for idx in 0..node.slots.len() {
+                let next = (idx..=last).find(|&idx| node.occupied[idx]).unwrap_or(last);
+                assert_eq!(node.slots[idx], node.slots[next]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut index = GappedIndex::<u64>::default();
+        assert!(index.is_empty());
+        assert!(!index.contains(&1));
+        assert!(!index.remove(&1));
+        assert_eq!(index.iter().count(), 0);
+        assert!(index.insert(1));
+        assert!(index.remove(&1));
+        assert_eq!(index.node_count(), 0);
+    }
+
+    #[test]
+    fn test_exponential_search() {
+        let node = DataNode::train((0..100_u64).map(|n| n * 2).collect());
+        for target in 0..210 {
+            let expected = node.slots.partition_point(|&key| key < target);
+            for pos in [0, 10, 50, node.slots.len() - 1] {
+                assert_eq!(node.lower_bound(&target, pos), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_btree_set() {
+        let mut index = GappedIndex::new();
+        let mut expected = BTreeSet::new();
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..20_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let key = state % 10_000;
+            if state.is_multiple_of(3) {
+                assert_eq!(index.remove(&key), expected.remove(&key), "{key}");
+            } else {
+                assert_eq!(index.insert(key), expected.insert(key), "{key}");
+            }
+        }
+        assert_same_keys(&index, &expected);
+        for key in 0..10_010 {
+            assert_eq!(index.contains(&key), expected.contains(&key));
+        }
+    }
+
+    #[test]
+    fn test_ascending_inserts() {
+        let mut index = GappedIndex::new();
+        for key in 0..5000_u64 {
+            assert!(index.insert(key * 3));
+        }
+        assert!(index.node_count() > 1);
+        assert_same_keys(&index, &(0..5000).map(|key| key * 3).collect());
+        for key in (0..5000_u64).rev() {
+            assert!(index.remove(&(key * 3)));
+        }
+        assert!(index.is_empty());
+    }
+
+    #[test]
+    fn test_skewed_keys_split() {
+        let mut index = GappedIndex::new();
+        let keys = (0..3000_u64).map(|n| n * n * n).collect::<Vec<_>>();
+        for (n, &key) in keys.iter().enumerate() {
+            index.insert(keys[(n * 7919) % keys.len()]);
+            assert!(index.contains(&keys[(n * 7919) % keys.len()]));
+            assert!(!index.contains(&(key + 1)));
+        }
+        assert_same_keys(&index, &keys.iter().copied().collect());
+        assert!(index.node_count() > 3);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let index = (0..3000_u64).rev().chain(0..10).collect::<GappedIndex<_>>();
+        assert_eq!(index.node_count(), 6);
+        assert_same_keys(&index, &(0..3000).collect());
+
+        let mut index = ["pear", "apple", "fig"]
+            .into_iter()
+            .map(String::from)
+            .collect::<GappedIndex<_>>();
+        index.extend(["banana".to_string(), "fig".to_string()]);
+        assert_eq!(
+            index.iter().map(String::as_str).collect::<Vec<_>>(),
+            ["apple", "banana", "fig", "pear"]
+        );
+        assert_eq!(index.get(&"fig".to_string()), Some(&"fig".to_string()));
+    }
+}
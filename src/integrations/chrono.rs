@@ -0,0 +1,155 @@
+use crate::InterpolationFactor;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone};
+use std::time::Duration;
+
+impl InterpolationFactor for NaiveDate {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.num_days_from_ce()
+            .interpolation_factor(&a.num_days_from_ce(), &b.num_days_from_ce())
+    }
+}
+
+impl InterpolationFactor for NaiveTime {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        time_delta_factor(
+            *self - NaiveTime::MIN,
+            *a - NaiveTime::MIN,
+            *b - NaiveTime::MIN,
+        )
+    }
+}
+
+impl InterpolationFactor for NaiveDateTime {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        time_delta_factor(*self - *a, TimeDelta::zero(), *b - *a)
+    }
+}
+
+impl<Tz: TimeZone> InterpolationFactor for DateTime<Tz> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.naive_utc()
+            .interpolation_factor(&a.naive_utc(), &b.naive_utc())
+    }
+}
+
+impl InterpolationFactor for TimeDelta {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        time_delta_factor(*self, *a, *b)
+    }
+}
+
+// Shifts the deltas so that `a` becomes zero and interpolates the non-negative offsets as
+// `std::time::Duration`s.
+fn time_delta_factor(mid: TimeDelta, a: TimeDelta, b: TimeDelta) -> f32 {
+    let offset = |delta: TimeDelta| {
+        delta
+            .checked_sub(&a)
+            .and_then(|delta| delta.to_std().ok())
+            .unwrap_or_default()
+    };
+    offset(mid).interpolation_factor(&Duration::ZERO, &offset(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn test_naive_date() {
+        let a = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let b = NaiveDate::from_ymd_opt(2024, 1, 11).unwrap();
+        let mid = NaiveDate::from_ymd_opt(2024, 1, 6).unwrap();
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(
+            [
+                (-100, 1, 1),
+                (1, 1, 1),
+                (1999, 12, 31),
+                (2000, 1, 1),
+                (2024, 2, 29),
+            ]
+            .into_iter()
+            .map(|(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap())
+            .collect(),
+        );
+    }
+
+    #[test]
+    fn test_naive_time() {
+        let a = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        let b = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let mid = NaiveTime::from_hms_opt(11, 0, 0).unwrap();
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(
+            [
+                (0, 0, 0, 0),
+                (0, 0, 0, 1),
+                (12, 30, 0, 0),
+                (23, 59, 59, 999_999_999),
+            ]
+            .into_iter()
+            .map(|(h, m, s, n)| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap())
+            .collect(),
+        );
+    }
+
+    #[test]
+    fn test_date_time() {
+        let a = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let b = a + TimeDelta::days(4);
+        let mid = a + TimeDelta::days(1);
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.25);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+        assert_eq!(
+            mid.and_utc()
+                .interpolation_factor(&a.and_utc(), &b.and_utc()),
+            0.25
+        );
+
+        let values = (0..20)
+            .map(|n| a + TimeDelta::hours(n * n * 1000) - TimeDelta::nanoseconds(n))
+            .collect::<Vec<_>>();
+        assert_consistent_with_ord(values.clone());
+        assert_consistent_with_ord(values.iter().map(|dt| dt.and_utc()).collect());
+
+        // `DateTime`s are ordered by their UTC instants, regardless of the offset.
+        let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+        let with_offset = |dt: &NaiveDateTime| dt.and_utc().with_timezone(&offset);
+        assert_consistent_with_ord(values.iter().map(with_offset).collect());
+        assert_eq!(
+            with_offset(&mid).interpolation_factor(&with_offset(&a), &with_offset(&b)),
+            0.25
+        );
+    }
+
+    #[test]
+    fn test_time_delta() {
+        let a = TimeDelta::seconds(-10);
+        let b = TimeDelta::seconds(10);
+        assert_eq!(TimeDelta::zero().interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(
+            [TimeDelta::MIN, TimeDelta::MAX, TimeDelta::zero()]
+                .into_iter()
+                .chain((-5..5).map(|n| TimeDelta::nanoseconds(n * 7)))
+                .chain((-5..5).map(|n| TimeDelta::days(n * 365)))
+                .collect(),
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! [`InterpolationFactor`](crate::InterpolationFactor) implementations for types of third-party
+//! crates. Each integration is behind an opt-in cargo feature named after the crate.
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "uuid")]
+mod uuid;
+
+// Checks that the interpolation factor of every value is within `[0.0, 1.0]` between any two
+// bounds around it, and that searching the sorted, deduplicated values returns the same index as
+// `binary_search`.
+#[cfg(all(
+    test,
+    any(
+        feature = "chrono",
+        feature = "num-bigint",
+        feature = "ordered-float",
+        feature = "rust_decimal",
+        feature = "time",
+        feature = "uuid",
+    )
+))]
+fn assert_consistent_with_ord<T>(mut values: Vec<T>)
+where
+    T: Ord + crate::InterpolationFactor + std::fmt::Debug,
+{
+    use crate::InterpolationSearch;
+
+    values.sort();
+    values.dedup();
+    for (i, a) in values.iter().enumerate() {
+        for (j, mid) in values.iter().enumerate().skip(i) {
+            for b in &values[j..] {
+                let f = mid.interpolation_factor(a, b);
+                assert!(
+                    (0.0..=1.0).contains(&f),
+                    "{mid:?} in [{a:?}, {b:?}] has factor {f}"
+                );
+            }
+        }
+    }
+    for value in &values {
+        assert_eq!(
+            values.interpolation_search(value),
+            values.binary_search(value),
+            "{value:?}"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::InterpolationFactor;
+use num_bigint::{BigInt, BigUint};
+
+impl InterpolationFactor for BigUint {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        if a >= b {
+            0.5
+        } else if self <= a {
+            0.0
+        } else if self >= b {
+            1.0
+        } else {
+            ratio(&(self - a), &(b - a))
+        }
+    }
+}
+
+impl InterpolationFactor for BigInt {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        if a >= b {
+            0.5
+        } else if self <= a {
+            0.0
+        } else if self >= b {
+            1.0
+        } else {
+            ratio((self - a).magnitude(), (b - a).magnitude())
+        }
+    }
+}
+
+// Divides `offset <= span` after dropping the bits of both that don't fit into an `u64`.
+fn ratio(offset: &BigUint, span: &BigUint) -> f32 {
+    let shift = span.bits().saturating_sub(u64::BITS.into());
+    let to_f64 = |n: BigUint| u64::try_from(&n).map_or(f64::NAN, |n| n as f64);
+    (to_f64(offset >> shift) / to_f64(span >> shift)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+
+    #[test]
+    fn test_big_uint() {
+        let n = |n: u32| BigUint::from(n);
+        assert_eq!(n(5).interpolation_factor(&n(0), &n(10)), 0.5);
+        assert_eq!(n(0).interpolation_factor(&n(0), &n(10)), 0.0);
+        assert_eq!(n(10).interpolation_factor(&n(0), &n(10)), 1.0);
+        assert_eq!(n(5).interpolation_factor(&n(5), &n(5)), 0.5);
+
+        let big = BigUint::from(1_u32) << 1000_u32;
+        assert_eq!((&big >> 1_u32).interpolation_factor(&n(0), &big), 0.5);
+        assert_eq!(
+            (&big + n(1)).interpolation_factor(&big, &(&big + n(4))),
+            0.25
+        );
+
+        assert_consistent_with_ord(
+            (0..10_u32)
+                .map(|n| (BigUint::from(3_u32) << (n * 50)) - n)
+                .chain([
+                    n(0),
+                    BigUint::from(u128::MAX),
+                    BigUint::from(u128::MAX) + n(1),
+                ])
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn test_big_int() {
+        let n = |n: i128| BigInt::from(n);
+        assert_eq!(n(0).interpolation_factor(&n(-10), &n(10)), 0.5);
+        assert_eq!(n(-10).interpolation_factor(&n(-10), &n(10)), 0.0);
+        assert_eq!(n(10).interpolation_factor(&n(-10), &n(10)), 1.0);
+        assert_eq!(n(5).interpolation_factor(&n(5), &n(5)), 0.5);
+
+        assert_consistent_with_ord(
+            (-10..10_i32)
+                .map(|n| BigInt::from(n) << (n.unsigned_abs() * 30))
+                .chain([n(0), n(i128::MIN), n(i128::MAX)])
+                .collect(),
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::InterpolationFactor;
+use ordered_float::{FloatCore, NotNan, OrderedFloat};
+
+impl<T: FloatCore> InterpolationFactor for OrderedFloat<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        float_factor(self.0, a.0, b.0)
+    }
+}
+
+impl<T: FloatCore> InterpolationFactor for NotNan<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        float_factor(**self, **a, **b)
+    }
+}
+
+// `OrderedFloat` orders NaN after every other value, including positive infinity. Infinite bounds
+// leave nothing to interpolate, so those fall back to the middle of the range.
+fn float_factor<T: FloatCore>(mid: T, a: T, b: T) -> f32 {
+    if mid.is_nan() {
+        return 1.0;
+    }
+    let f = ((mid - a) / (b - a)).to_f32().unwrap_or(0.5);
+    if f.is_nan() {
+        0.5
+    } else {
+        f.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+
+    #[test]
+    fn test_ordered_float() {
+        let f = OrderedFloat::<f64>;
+        assert_eq!(f(0.5).interpolation_factor(&f(0.0), &f(2.0)), 0.25);
+        assert_eq!(f(0.0).interpolation_factor(&f(0.0), &f(2.0)), 0.0);
+        assert_eq!(f(2.0).interpolation_factor(&f(0.0), &f(2.0)), 1.0);
+        assert_eq!(f(1.0).interpolation_factor(&f(1.0), &f(1.0)), 0.5);
+        assert_eq!(f(f64::NAN).interpolation_factor(&f(0.0), &f(f64::NAN)), 1.0);
+        assert_eq!(f(1.0).interpolation_factor(&f(0.0), &f(f64::INFINITY)), 0.0);
+
+        assert_consistent_with_ord(
+            [
+                f32::NEG_INFINITY,
+                f32::MIN,
+                -1.5,
+                -0.0,
+                0.0,
+                f32::MIN_POSITIVE,
+                1.0,
+                1e30,
+                f32::MAX,
+                f32::INFINITY,
+                f32::NAN,
+            ]
+            .map(OrderedFloat)
+            .to_vec(),
+        );
+    }
+
+    #[test]
+    fn test_not_nan() {
+        let f = |x: f64| NotNan::new(x).unwrap();
+        assert_eq!(f(0.5).interpolation_factor(&f(0.0), &f(2.0)), 0.25);
+        assert_eq!(f(-1.0).interpolation_factor(&f(-2.0), &f(2.0)), 0.25);
+        assert_eq!(f(1.0).interpolation_factor(&f(1.0), &f(1.0)), 0.5);
+
+        assert_consistent_with_ord(
+            [
+                f64::NEG_INFINITY,
+                f64::MIN,
+                -1.5,
+                0.0,
+                1.0,
+                1e300,
+                f64::MAX,
+                f64::INFINITY,
+            ]
+            .map(f)
+            .to_vec(),
+        );
+    }
+}
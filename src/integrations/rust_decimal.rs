@@ -0,0 +1,57 @@
+use crate::InterpolationFactor;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+impl InterpolationFactor for Decimal {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        if a == b {
+            return 0.5;
+        }
+        let mid = (*self).clamp(*a, *b);
+        // The differences can overflow at the edges of the representable range, in which case the
+        // values are interpolated as floats instead.
+        match (mid.checked_sub(*a), b.checked_sub(*a)) {
+            (Some(offset), Some(span)) => offset.checked_div(span).and_then(|f| f.to_f32()),
+            _ => None,
+        }
+        .or_else(|| {
+            let (mid, a, b) = (mid.to_f64()?, a.to_f64()?, b.to_f64()?);
+            Some(((mid - a) / (b - a)) as f32)
+        })
+        .map_or(0.5, |f| f.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+
+    #[test]
+    fn test_decimal() {
+        let d = |n: i64, scale: u32| Decimal::new(n, scale);
+        assert_eq!(d(150, 2).interpolation_factor(&d(1, 0), &d(2, 0)), 0.5);
+        assert_eq!(d(1, 0).interpolation_factor(&d(1, 0), &d(2, 0)), 0.0);
+        assert_eq!(d(2, 0).interpolation_factor(&d(1, 0), &d(2, 0)), 1.0);
+        assert_eq!(d(1, 0).interpolation_factor(&d(100, 2), &d(1, 0)), 0.5);
+
+        // Small steps on top of a large value are preserved.
+        let base = Decimal::from(u64::MAX) * d(100, 0);
+        let f = (base + d(1, 2)).interpolation_factor(&base, &(base + d(4, 2)));
+        assert_eq!(f, 0.25);
+
+        assert_eq!(
+            Decimal::ZERO.interpolation_factor(&Decimal::MIN, &Decimal::MAX),
+            0.5
+        );
+        assert_consistent_with_ord(vec![
+            Decimal::MIN,
+            d(-1, 28),
+            Decimal::ZERO,
+            d(1, 28),
+            d(15, 1),
+            d(150, 2),
+            d(i64::MAX, 0),
+            Decimal::MAX,
+        ]);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::InterpolationFactor;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+impl InterpolationFactor for Date {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.to_julian_day()
+            .interpolation_factor(&a.to_julian_day(), &b.to_julian_day())
+    }
+}
+
+impl InterpolationFactor for Time {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        (*self - Time::MIDNIGHT)
+            .interpolation_factor(&(*a - Time::MIDNIGHT), &(*b - Time::MIDNIGHT))
+    }
+}
+
+impl InterpolationFactor for PrimitiveDateTime {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        (*self - *a).interpolation_factor(&Duration::ZERO, &(*b - *a))
+    }
+}
+
+impl InterpolationFactor for OffsetDateTime {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        (*self - *a).interpolation_factor(&Duration::ZERO, &(*b - *a))
+    }
+}
+
+impl InterpolationFactor for Duration {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.whole_nanoseconds()
+            .interpolation_factor(&a.whole_nanoseconds(), &b.whole_nanoseconds())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+    use time::{Month, UtcOffset};
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date() {
+        let a = date(2024, Month::January, 1);
+        let b = date(2024, Month::January, 11);
+        let mid = date(2024, Month::January, 6);
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(vec![
+            Date::MIN,
+            date(-100, Month::March, 1),
+            date(1999, Month::December, 31),
+            date(2000, Month::January, 1),
+            date(2024, Month::February, 29),
+            Date::MAX,
+        ]);
+    }
+
+    #[test]
+    fn test_time() {
+        let a = Time::from_hms(10, 0, 0).unwrap();
+        let b = Time::from_hms(12, 0, 0).unwrap();
+        let mid = Time::from_hms(11, 0, 0).unwrap();
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(
+            [
+                (0, 0, 0, 0),
+                (0, 0, 0, 1),
+                (12, 30, 0, 0),
+                (23, 59, 59, 999_999_999),
+            ]
+            .into_iter()
+            .map(|(h, m, s, n)| Time::from_hms_nano(h, m, s, n).unwrap())
+            .collect(),
+        );
+    }
+
+    #[test]
+    fn test_date_time() {
+        let a = PrimitiveDateTime::new(date(2024, Month::January, 1), Time::MIDNIGHT);
+        let b = a + Duration::days(4);
+        let mid = a + Duration::days(1);
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.25);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+        assert_eq!(
+            mid.assume_utc()
+                .interpolation_factor(&a.assume_utc(), &b.assume_utc()),
+            0.25
+        );
+
+        let values = (0..20)
+            .map(|n| a + Duration::hours(n * n * 1000) - Duration::nanoseconds(n))
+            .collect::<Vec<_>>();
+        assert_consistent_with_ord(values.clone());
+
+        // `OffsetDateTime`s are ordered by their UTC instants, regardless of the offset.
+        let offset = UtcOffset::from_hms(5, 0, 0).unwrap();
+        assert_consistent_with_ord(
+            values
+                .iter()
+                .enumerate()
+                .map(|(n, dt)| match n % 2 {
+                    0 => dt.assume_utc(),
+                    _ => dt.assume_offset(offset),
+                })
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        let a = Duration::seconds(-10);
+        let b = Duration::seconds(10);
+        assert_eq!(Duration::ZERO.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        assert_consistent_with_ord(
+            [Duration::MIN, Duration::MAX, Duration::ZERO]
+                .into_iter()
+                .chain((-5..5).map(|n| Duration::nanoseconds(n * 7)))
+                .chain((-5..5).map(|n| Duration::days(n * 365)))
+                .collect(),
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::InterpolationFactor;
+use uuid::Uuid;
+
+// `Uuid` is ordered by its bytes, which is the same as the order of its big-endian `u128` value.
+impl InterpolationFactor for Uuid {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.as_u128()
+            .interpolation_factor(&a.as_u128(), &b.as_u128())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::assert_consistent_with_ord;
+
+    #[test]
+    fn test_uuid() {
+        let a = Uuid::from_u128(0);
+        let b = Uuid::from_u128(1000);
+        assert_eq!(Uuid::from_u128(500).interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+    }
+
+    #[test]
+    fn test_uuid_ord() {
+        assert_consistent_with_ord(
+            [
+                0,
+                1,
+                0xff,
+                0x0100,
+                u64::MAX as u128,
+                1 << 100,
+                u128::MAX - 1,
+                u128::MAX,
+            ]
+            .into_iter()
+            .map(Uuid::from_u128)
+            .chain([Uuid::from_bytes([0x80; 16]), Uuid::from_bytes([0x7f; 16])])
+            .collect(),
+        );
+    }
+}
@@ -1,4 +1,15 @@
-use std::{str::Chars, time::SystemTime};
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    ffi::{OsStr, OsString},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::{NonZero, Saturating, Wrapping},
+    path::{Path, PathBuf},
+    rc::Rc,
+    str::Chars,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
 /// Extends types with an `interpolation_factor` method to calculate the interpolation factor of a
 /// value between two given values.
@@ -9,7 +20,7 @@ use std::{str::Chars, time::SystemTime};
 /// the `[first, last]` range to better reduce the search space.
 ///
 /// This crate comes with trivial implementations for integer types and custom implementations for
-/// well-known "linear" types, such as `char` and `SystemTime`.
+/// well-known "linear" types, such as `char`, `SystemTime`, IP addresses, strings and paths.
 ///
 /// # Examples
 ///
@@ -37,8 +48,9 @@ macro_rules! trivially_interpolation_factor {
                 if a == b {
                     0.5
                 } else {
+                    // Divide in `f64`, as the differences of 128-bit integers overflow `f32`.
                     let mid = self.clamp(a, b);
-                    a.abs_diff(*mid) as f32 / a.abs_diff(*b) as f32
+                    (a.abs_diff(*mid) as f64 / a.abs_diff(*b) as f64) as f32
                 }
             }
         }
@@ -58,55 +70,291 @@ trivially_interpolation_factor!(i64);
 trivially_interpolation_factor!(i128);
 trivially_interpolation_factor!(isize);
 
+macro_rules! non_zero_interpolation_factor {
+    ($($t:ty),*) => {
+        $(
+            impl InterpolationFactor for NonZero<$t> {
+                fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                    self.get().interpolation_factor(&a.get(), &b.get())
+                }
+            }
+        )*
+    };
+}
+
+non_zero_interpolation_factor!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: InterpolationFactor> InterpolationFactor for Wrapping<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.0.interpolation_factor(&a.0, &b.0)
+    }
+}
+
+impl<T: InterpolationFactor> InterpolationFactor for Saturating<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.0.interpolation_factor(&a.0, &b.0)
+    }
+}
+
+// The bounds of a `Reverse` range are the other way round for the inner type.
+impl<T: InterpolationFactor> InterpolationFactor for Reverse<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        1.0 - self.0.interpolation_factor(&b.0, &a.0)
+    }
+}
+
+// `None` is ordered before any `Some`. There's no way to tell how far a `Some` value is from a
+// `None` lower bound, so that case falls back to the middle of the range.
+impl<T: InterpolationFactor + PartialEq> InterpolationFactor for Option<T> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        match (self, a, b) {
+            (Some(mid), Some(a), Some(b)) => mid.interpolation_factor(a, b),
+            (None, _, Some(_)) => 0.0,
+            (Some(mid), None, Some(b)) if mid == b => 1.0,
+            (Some(_), _, None) => 1.0,
+            _ => 0.5,
+        }
+    }
+}
+
+impl InterpolationFactor for bool {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        u8::from(*self).interpolation_factor(&u8::from(*a), &u8::from(*b))
+    }
+}
+
 impl InterpolationFactor for char {
     fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
         u32::from(*self).interpolation_factor(&u32::from(*a), &u32::from(*b))
     }
 }
 
+impl InterpolationFactor for Ipv4Addr {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        u32::from(*self).interpolation_factor(&u32::from(*a), &u32::from(*b))
+    }
+}
+
+impl InterpolationFactor for Ipv6Addr {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        u128::from(*self).interpolation_factor(&u128::from(*a), &u128::from(*b))
+    }
+}
+
+macro_rules! socket_addr_interpolation_factor {
+    ($t:ty) => {
+        impl InterpolationFactor for $t {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                if a.ip() != b.ip() {
+                    self.ip().interpolation_factor(a.ip(), b.ip())
+                } else {
+                    self.port().interpolation_factor(&a.port(), &b.port())
+                }
+            }
+        }
+    };
+}
+
+socket_addr_interpolation_factor!(SocketAddrV4);
+socket_addr_interpolation_factor!(SocketAddrV6);
+
+// `IpAddr` and `SocketAddr` order every IPv4 address before every IPv6 address. When the bounds
+// straddle both families, the lower half of the factor range is assigned to the IPv4 part and the
+// upper half to the IPv6 part.
+macro_rules! dual_stack_interpolation_factor {
+    ($t:ident, $v4_max:expr, $v6_min:expr) => {
+        impl InterpolationFactor for $t {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                match (self, a, b) {
+                    ($t::V4(mid), $t::V4(a), $t::V4(b)) => mid.interpolation_factor(a, b),
+                    ($t::V6(mid), $t::V6(a), $t::V6(b)) => mid.interpolation_factor(a, b),
+                    ($t::V4(mid), $t::V4(a), $t::V6(_)) => {
+                        0.5 * mid.interpolation_factor(a, &$v4_max)
+                    }
+                    ($t::V6(mid), $t::V4(_), $t::V6(b)) => {
+                        0.5 + 0.5 * mid.interpolation_factor(&$v6_min, b)
+                    }
+                    (mid, a, _) if mid < a => 0.0,
+                    (mid, _, b) if mid > b => 1.0,
+                    _ => 0.5,
+                }
+            }
+        }
+    };
+}
+
+dual_stack_interpolation_factor!(IpAddr, Ipv4Addr::BROADCAST, Ipv6Addr::UNSPECIFIED);
+dual_stack_interpolation_factor!(
+    SocketAddr,
+    SocketAddrV4::new(Ipv4Addr::BROADCAST, u16::MAX),
+    SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0)
+);
+
 impl InterpolationFactor for SystemTime {
     fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
         if a == b {
             0.5
         } else {
-            self.duration_since(*a)
-                .unwrap_or_default()
-                .div_duration_f32(b.duration_since(*a).unwrap_or_default())
+            duration_ratio(
+                self.duration_since(*a).unwrap_or_default(),
+                b.duration_since(*a).unwrap_or_default(),
+            )
+        }
+    }
+}
+
+impl InterpolationFactor for Instant {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        if a == b {
+            0.5
+        } else {
+            duration_ratio(
+                self.saturating_duration_since(*a),
+                b.saturating_duration_since(*a),
+            )
+        }
+    }
+}
+
+impl InterpolationFactor for Duration {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        if a == b {
+            0.5
+        } else {
+            duration_ratio(self.saturating_sub(*a), b.saturating_sub(*a))
         }
     }
 }
 
+// Divides two durations through their nanosecond counts in `f64`. Unlike `div_duration_f32`,
+// which converts both operands to `f32` seconds, this keeps nanosecond resolution for spans of up
+// to ~104 days.
+fn duration_ratio(offset: Duration, span: Duration) -> f32 {
+    (offset.as_nanos() as f64 / span.as_nanos() as f64).min(1.0) as f32
+}
+
 impl InterpolationFactor for Chars<'_> {
     fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
-        match self
-            .clone()
-            .zip(a.clone())
-            .zip(b.clone())
-            .map(|((mid, a), b)| (mid, a, b))
-            .find(|(_, a, b)| a != b)
-        {
+        match first_difference(self.clone(), a.clone(), b.clone()) {
             Some((mid, a, b)) => mid.interpolation_factor(&a, &b),
             None => 0.5,
         }
     }
 }
 
-impl InterpolationFactor for &str {
+impl InterpolationFactor for str {
     fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
         self.chars().interpolation_factor(&a.chars(), &b.chars())
     }
 }
 
-impl InterpolationFactor for String {
+macro_rules! forward_interpolation_factor {
+    ($t:ty) => {
+        impl<T: InterpolationFactor + ?Sized> InterpolationFactor for $t {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                T::interpolation_factor(self, a, b)
+            }
+        }
+    };
+}
+
+forward_interpolation_factor!(&T);
+forward_interpolation_factor!(&mut T);
+forward_interpolation_factor!(Box<T>);
+forward_interpolation_factor!(Rc<T>);
+forward_interpolation_factor!(Arc<T>);
+
+macro_rules! str_like_interpolation_factor {
+    ($t:ty) => {
+        impl InterpolationFactor for $t {
+            fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+                str::interpolation_factor(self, a, b)
+            }
+        }
+    };
+}
+
+str_like_interpolation_factor!(String);
+str_like_interpolation_factor!(Cow<'_, str>);
+
+// `OsStr` is ordered by its encoded bytes, which on Unix is the raw byte representation.
+impl InterpolationFactor for OsStr {
     fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
-        self.chars().interpolation_factor(&a.chars(), &b.chars())
+        match first_difference(
+            self.as_encoded_bytes().iter(),
+            a.as_encoded_bytes().iter(),
+            b.as_encoded_bytes().iter(),
+        ) {
+            Some((mid, a, b)) => mid.interpolation_factor(a, b),
+            None => 0.5,
+        }
+    }
+}
+
+impl InterpolationFactor for OsString {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.as_os_str().interpolation_factor(a, b)
+    }
+}
+
+// `Path` is ordered component-wise rather than byte-wise, so the factor is taken from the first
+// component that differs between the bounds.
+impl InterpolationFactor for Path {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        match first_difference(self.components(), a.components(), b.components()) {
+            Some((mid, a, b)) => mid
+                .as_os_str()
+                .interpolation_factor(a.as_os_str(), b.as_os_str()),
+            None => 0.5,
+        }
+    }
+}
+
+impl InterpolationFactor for PathBuf {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        self.as_path().interpolation_factor(a, b)
+    }
+}
+
+// Byte arrays, such as digests, are ordered lexicographically. The bytes before the first one
+// that differs between the bounds are shared by everything in between, so the factor is taken
+// from the following eight bytes as a big-endian integer, which keeps 64 bits of resolution.
+impl<const N: usize> InterpolationFactor for [u8; N] {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        let Some(start) = (0..N).find(|&idx| a[idx] != b[idx]) else {
+            return 0.5;
+        };
+        let window = |bytes: &Self| {
+            let mut buf = [0; 8];
+            let tail = &bytes[start..N.min(start + 8)];
+            buf[..tail.len()].copy_from_slice(tail);
+            u64::from_be_bytes(buf)
+        };
+        window(self).interpolation_factor(&window(a), &window(b))
     }
 }
 
+// Walks three lexicographically ordered sequences in lockstep and returns the elements at the
+// first position where the bounds `a` and `b` differ.
+pub(crate) fn first_difference<I: Iterator>(
+    mid: I,
+    a: I,
+    b: I,
+) -> Option<(I::Item, I::Item, I::Item)>
+where
+    I::Item: PartialEq,
+{
+    mid.zip(a)
+        .zip(b)
+        .map(|((mid, a), b)| (mid, a, b))
+        .find(|(_, a, b)| a != b)
+}
+
 #[cfg(test)]
+// The oldest tests predate the `str` impl and are kept as they were written.
+#[allow(clippy::needless_borrow)]
 mod tests {
     use super::*;
-    use std::time::Duration;
 
     #[test]
     fn test_u8() {
@@ -146,6 +394,8 @@ mod tests {
         assert_eq!(0.interpolation_factor(&0, &10000000), 0.0);
         assert_eq!(10000000.interpolation_factor(&0, &10000000), 1.0);
         assert_eq!(5000000.interpolation_factor(&5000000, &5000000), 0.5);
+        assert_eq!(u128::MAX.interpolation_factor(&0, &u128::MAX), 1.0);
+        assert_eq!((u128::MAX / 2).interpolation_factor(&0, &u128::MAX), 0.5);
     }
 
     #[test]
@@ -207,6 +457,85 @@ mod tests {
         assert_eq!((-500).interpolation_factor(&(-500), &(-500)), 0.5);
     }
 
+    #[test]
+    fn test_non_zero() {
+        let nz = |n| NonZero::<u32>::new(n).unwrap();
+        assert_eq!(nz(6).interpolation_factor(&nz(1), &nz(11)), 0.5);
+        assert_eq!(nz(1).interpolation_factor(&nz(1), &nz(11)), 0.0);
+        assert_eq!(nz(11).interpolation_factor(&nz(1), &nz(11)), 1.0);
+        assert_eq!(nz(6).interpolation_factor(&nz(6), &nz(6)), 0.5);
+
+        let nz = |n| NonZero::<i64>::new(n).unwrap();
+        assert_eq!(nz(-5).interpolation_factor(&nz(-10), &nz(-1)), 5.0 / 9.0);
+    }
+
+    #[test]
+    fn test_wrapping_and_saturating() {
+        assert_eq!(
+            Wrapping(5).interpolation_factor(&Wrapping(0), &Wrapping(10)),
+            0.5
+        );
+        assert_eq!(
+            Wrapping(0).interpolation_factor(&Wrapping(0), &Wrapping(10)),
+            0.0
+        );
+        assert_eq!(
+            Wrapping(5).interpolation_factor(&Wrapping(5), &Wrapping(5)),
+            0.5
+        );
+        assert_eq!(
+            Saturating(5).interpolation_factor(&Saturating(0), &Saturating(10)),
+            0.5
+        );
+        assert_eq!(
+            Saturating(10).interpolation_factor(&Saturating(0), &Saturating(10)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(
+            Reverse(10).interpolation_factor(&Reverse(10), &Reverse(0)),
+            0.0
+        );
+        assert_eq!(
+            Reverse(0).interpolation_factor(&Reverse(10), &Reverse(0)),
+            1.0
+        );
+        assert_eq!(
+            Reverse(6).interpolation_factor(&Reverse(8), &Reverse(0)),
+            0.25
+        );
+        assert_eq!(
+            Reverse(5).interpolation_factor(&Reverse(5), &Reverse(5)),
+            0.5
+        );
+        assert_eq!(
+            Reverse("ccc").interpolation_factor(&Reverse("eee"), &Reverse("aaa")),
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(Some(5).interpolation_factor(&Some(0), &Some(10)), 0.5);
+        assert_eq!(None.interpolation_factor(&None, &Some(10)), 0.0);
+        assert_eq!(Some(10).interpolation_factor(&None, &Some(10)), 1.0);
+        assert_eq!(Some(5).interpolation_factor(&None, &Some(10)), 0.5);
+        assert_eq!(Some(5).interpolation_factor(&None, &None), 1.0);
+        assert_eq!(None::<i32>.interpolation_factor(&None, &None), 0.5);
+        assert_eq!(Some(5).interpolation_factor(&Some(5), &Some(5)), 0.5);
+    }
+
+    #[test]
+    fn test_bool() {
+        assert_eq!(false.interpolation_factor(&false, &true), 0.0);
+        assert_eq!(true.interpolation_factor(&false, &true), 1.0);
+        assert_eq!(true.interpolation_factor(&true, &true), 0.5);
+        assert_eq!(false.interpolation_factor(&false, &false), 0.5);
+    }
+
     #[test]
     fn test_char() {
         assert_eq!('c'.interpolation_factor(&'a', &'e'), 0.5);
@@ -215,6 +544,72 @@ mod tests {
         assert_eq!('c'.interpolation_factor(&'c', &'c'), 0.5);
     }
 
+    #[test]
+    fn test_ipv4_addr() {
+        let a = Ipv4Addr::new(10, 0, 0, 0);
+        let b = Ipv4Addr::new(10, 0, 0, 200);
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 100).interpolation_factor(&a, &b),
+            0.5
+        );
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+    }
+
+    #[test]
+    fn test_ipv6_addr() {
+        let a = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        let b = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x100);
+        let mid = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x80);
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        assert_eq!(a.interpolation_factor(&a, &b), 0.0);
+        assert_eq!(b.interpolation_factor(&a, &b), 1.0);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+    }
+
+    #[test]
+    fn test_ip_addr() {
+        let v4_a = IpAddr::from([10, 0, 0, 0]);
+        let v4_b = IpAddr::from([10, 0, 0, 200]);
+        let v4_mid = IpAddr::from([10, 0, 0, 100]);
+        assert_eq!(v4_mid.interpolation_factor(&v4_a, &v4_b), 0.5);
+
+        let v6_a = IpAddr::from(Ipv6Addr::UNSPECIFIED);
+        let v6_b = IpAddr::from(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0x100));
+        let v6_mid = IpAddr::from(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0x80));
+        assert_eq!(v6_mid.interpolation_factor(&v6_a, &v6_b), 0.5);
+
+        // IPv4 addresses occupy the lower half when the bounds straddle both families.
+        let f = v4_mid.interpolation_factor(&v4_a, &v6_b);
+        assert!((0.0..=0.5).contains(&f));
+        assert_eq!(v4_a.interpolation_factor(&v4_a, &v6_b), 0.0);
+        assert_eq!(v6_mid.interpolation_factor(&v4_a, &v6_b), 0.75);
+        assert_eq!(v6_b.interpolation_factor(&v4_a, &v6_b), 1.0);
+
+        // Values outside of the bounds' families are clamped.
+        assert_eq!(v6_mid.interpolation_factor(&v4_a, &v4_b), 1.0);
+        assert_eq!(v4_mid.interpolation_factor(&v6_a, &v6_b), 0.0);
+    }
+
+    #[test]
+    fn test_socket_addr() {
+        let a = SocketAddr::from(([10, 0, 0, 0], 80));
+        let b = SocketAddr::from(([10, 0, 0, 200], 80));
+        let mid = SocketAddr::from(([10, 0, 0, 100], 443));
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+
+        let a = SocketAddr::from(([10, 0, 0, 1], 0));
+        let b = SocketAddr::from(([10, 0, 0, 1], 1000));
+        let mid = SocketAddr::from(([10, 0, 0, 1], 250));
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.25);
+        assert_eq!(a.interpolation_factor(&a, &a), 0.5);
+
+        let v6 = SocketAddr::from((Ipv6Addr::LOCALHOST, 80));
+        assert_eq!(v6.interpolation_factor(&a, &v6), 1.0);
+        assert!(a.interpolation_factor(&a, &v6) <= 0.5);
+    }
+
     #[test]
     fn test_system_time() {
         let t0 = SystemTime::now();
@@ -231,6 +626,55 @@ mod tests {
         assert_eq!(t3.interpolation_factor(&t3, &t4), 0.5);
     }
 
+    #[test]
+    fn test_instant() {
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_secs(1);
+        let t2 = t0 + Duration::from_secs(2);
+        assert_eq!(t1.interpolation_factor(&t0, &t2), 0.5);
+        assert_eq!(t0.interpolation_factor(&t0, &t2), 0.0);
+        assert_eq!(t2.interpolation_factor(&t0, &t2), 1.0);
+        assert_eq!(t1.interpolation_factor(&t1, &t1), 0.5);
+
+        let t3 = Instant::now();
+        let t4 = t3;
+        assert_eq!(t3.interpolation_factor(&t3, &t4), 0.5);
+    }
+
+    #[test]
+    fn test_duration() {
+        let d0 = Duration::ZERO;
+        let d1 = Duration::from_millis(500);
+        let d2 = Duration::from_secs(1);
+        assert_eq!(d1.interpolation_factor(&d0, &d2), 0.5);
+        assert_eq!(d0.interpolation_factor(&d0, &d2), 0.0);
+        assert_eq!(d2.interpolation_factor(&d0, &d2), 1.0);
+        assert_eq!(d1.interpolation_factor(&d1, &d1), 0.5);
+        assert_eq!(d2.interpolation_factor(&d0, &d1), 1.0);
+        assert_eq!(d0.interpolation_factor(&d1, &d2), 0.0);
+    }
+
+    #[test]
+    fn test_duration_precision() {
+        // Nanosecond steps on top of a large offset must not be rounded away.
+        let base = Duration::from_secs(1_000_000);
+        let a = base;
+        let b = base + Duration::from_nanos(4);
+        assert_eq!(
+            (base + Duration::from_nanos(1)).interpolation_factor(&a, &b),
+            0.25
+        );
+        assert_eq!(
+            (base + Duration::from_nanos(3)).interpolation_factor(&a, &b),
+            0.75
+        );
+
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::new(3600, 1);
+        let t2 = t0 + Duration::new(7200, 2);
+        assert_eq!(t1.interpolation_factor(&t0, &t2), 0.5);
+    }
+
     #[test]
     fn test_str() {
         assert_eq!("ccc".interpolation_factor(&"aaa", &"eee"), 0.5);
@@ -256,4 +700,112 @@ mod tests {
         assert_eq!(s3.interpolation_factor(&s2, &s3), 1.0);
         assert_eq!(s1.interpolation_factor(&s1.clone(), &s1), 0.5);
     }
+
+    #[test]
+    fn test_str_like() {
+        let (s1, s2, s3) = ("ccc", "aaa", "eee");
+        assert_eq!(
+            Cow::from(s1).interpolation_factor(&Cow::from(s2), &Cow::Owned(s3.to_string())),
+            0.5
+        );
+        assert_eq!(
+            Box::<str>::from(s1).interpolation_factor(&s2.into(), &s3.into()),
+            0.5
+        );
+        assert_eq!(
+            Rc::<str>::from(s2).interpolation_factor(&s2.into(), &s3.into()),
+            0.0
+        );
+        assert_eq!(
+            Arc::<str>::from(s3).interpolation_factor(&s2.into(), &s3.into()),
+            1.0
+        );
+        assert_eq!(
+            Arc::<str>::from(s1).interpolation_factor(&s1.into(), &s1.into()),
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_os_str() {
+        let s1 = OsStr::new("ccc");
+        let s2 = OsStr::new("aaa");
+        let s3 = OsStr::new("eee");
+        assert_eq!(s1.interpolation_factor(s2, s3), 0.5);
+        assert_eq!(s2.interpolation_factor(s2, s3), 0.0);
+        assert_eq!(s3.interpolation_factor(s2, s3), 1.0);
+        assert_eq!(s1.interpolation_factor(s1, s1), 0.5);
+        assert_eq!(
+            OsString::from("abc").interpolation_factor(&"abb".into(), &"abd".into()),
+            0.5
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let p1 = Path::new("/usr/lib/c");
+        let p2 = Path::new("/usr/lib/a");
+        let p3 = Path::new("/usr/lib/e");
+        assert_eq!(p1.interpolation_factor(p2, p3), 0.5);
+        assert_eq!(p2.interpolation_factor(p2, p3), 0.0);
+        assert_eq!(p3.interpolation_factor(p2, p3), 1.0);
+        assert_eq!(p1.interpolation_factor(p1, p1), 0.5);
+
+        // Paths are ordered by components: "a/b" < "a-b" even though '/' > '-'.
+        let a = Path::new("a/b");
+        let b = Path::new("a-b");
+        assert!(a < b);
+        let f = PathBuf::from("a/c").interpolation_factor(&a.into(), &b.into());
+        assert!((0.0..=1.0).contains(&f));
+    }
+
+    #[test]
+    fn test_byte_arrays() {
+        assert_eq!(
+            [0x80, 0].interpolation_factor(&[0, 0], &[0xff, 0xff]),
+            0.5000076
+        );
+        assert_eq!([7, 0x40].interpolation_factor(&[7, 0], &[7, 0x80]), 0.5);
+        assert_eq!([1, 2, 3].interpolation_factor(&[1, 2, 3], &[1, 2, 3]), 0.5);
+        assert_eq!([0_u8; 0].interpolation_factor(&[], &[]), 0.5);
+
+        // Bytes past the eight-byte window don't matter.
+        let a = [0_u8; 32];
+        let mut b = [0xff_u8; 32];
+        let mut mid = [0_u8; 32];
+        mid[0] = 0x80;
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+        b[..4].fill(0);
+        mid = [0; 32];
+        mid[4] = 0x80;
+        mid[20] = 0xff;
+        assert_eq!(mid.interpolation_factor(&a, &b), 0.5);
+    }
+
+    #[test]
+    fn test_references_and_smart_pointers() {
+        let (mid, a, b) = (&5, &0, &10);
+        assert_eq!(<&i32>::interpolation_factor(&mid, &a, &b), 0.5);
+        let (mut mid, mut a, mut b) = (5, 0, 10);
+        let (mid, a, b) = (&mut mid, &mut a, &mut b);
+        assert_eq!(<&mut i32>::interpolation_factor(&mid, &a, &b), 0.5);
+        assert_eq!(
+            Box::new(5).interpolation_factor(&Box::new(0), &Box::new(10)),
+            0.5
+        );
+        assert_eq!(
+            Rc::new(0).interpolation_factor(&Rc::new(0), &Rc::new(10)),
+            0.0
+        );
+        assert_eq!(
+            Arc::new(10).interpolation_factor(&Arc::new(0), &Arc::new(10)),
+            1.0
+        );
+        assert_eq!(
+            Arc::new(5).interpolation_factor(&Arc::new(5), &Arc::new(5)),
+            0.5
+        );
+        let (mid, a, b) = (&&'c', &&'a', &&'e');
+        assert_eq!(<&&char>::interpolation_factor(&mid, &a, &b), 0.5);
+    }
 }
@@ -1,10 +1,17 @@
-use crate::InterpolationFactor;
-use std::cmp::{
-    Ord,
-    Ordering::{Equal, Greater, Less},
+use crate::{Distance, InterpolationFactor};
+use std::{
+    cmp::{
+        Ord,
+        Ordering::{Equal, Greater, Less},
+    },
+    collections::VecDeque,
+    ops::{Bound, Range, RangeBounds},
 };
 
-pub trait InterpolationSearch<T> {
+/// Extends slices and `VecDeque`s with interpolation search methods.
+///
+/// The trait is sealed, so that methods can be added to it without breaking other crates: it can't be implemented outside of this crate.
+pub trait InterpolationSearch<T>: private::Sealed {
     /// Interpolation searches this slice for a given element. If the slice is not sorted, the returned result is unspecified and meaningless.
     ///
     /// The interface of this funciton is similar to its `binary_search` counterpart. If the value is found then `Result::Ok` is returned, containing the index of the matching element. If there are multiple matches, then any one of the matches could be returned. The index is chosen deterministically, but is subject to change in future versions of the crate. If the value is not found then `Result::Err` is returned, containing the index where a matching element could be inserted while maintaining sorted order.
@@ -30,6 +37,8 @@ pub trait InterpolationSearch<T> {
     ///
     /// The interface of this funciton is similar to its `binary_search_by_key` counterpart. If the value is found then `Result::Ok` is returned, containing the index of the matching element. If there are multiple matches, then any one of the matches could be returned. The index is chosen deterministically, but is subject to change in future versions of the crate. If the value is not found then `Result::Err` is returned, containing the index where a matching element could be inserted while maintaining sorted by key order.
     ///
+    /// The key may be unsized, so that e.g. a slice of `String`s can be searched for a `&str`.
+    ///
     /// **Examples**
     ///
     /// ```
@@ -46,7 +55,204 @@ pub trait InterpolationSearch<T> {
     /// ```
     fn interpolation_search_by_key<K, F>(&self, target: &K, f: F) -> Result<usize, usize>
     where
-        K: Ord + InterpolationFactor,
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K;
+
+    /// Returns the range of indices of the elements within `range`, which may have inclusive, exclusive or unbounded ends. If the slice is not sorted, the returned range is unspecified and meaningless.
+    ///
+    /// Both ends are located with interpolation search, and the end of the range is only searched for after its start. If `range` is empty or inverted, the returned range is empty and starts where the elements after its start would be.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_range(1..5), 1..5);
+    /// assert_eq!(arr.interpolation_range(4..=13), 5..8);
+    /// assert_eq!(arr.interpolation_range(..2), 0..3);
+    /// assert_eq!(arr.interpolation_range(30..), 9..11);
+    /// assert_eq!(arr.interpolation_range(6..8), 6..6);
+    /// ```
+    fn interpolation_range<R>(&self, range: R) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor,
+        R: RangeBounds<T>;
+
+    /// Returns the range of indices of the elements with keys within `range`, using a key extraction function. If the slice is not sorted by keys, the returned range is unspecified and meaningless.
+    ///
+    /// The range is located as in [`interpolation_range`](InterpolationSearch::interpolation_range).
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let readings = [(100, 1.5), (110, 1.7), (120, 1.6), (130, 2.0), (140, 2.2)];
+    ///
+    /// assert_eq!(readings.interpolation_range_by_key(105..=130, |(t, _)| t), 1..4);
+    /// assert_eq!(readings.interpolation_range_by_key(..110, |(t, _)| t), 0..1);
+    /// ```
+    fn interpolation_range_by_key<K, R, F>(&self, range: R, f: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        R: RangeBounds<K>,
+        F: FnMut(&T) -> &K;
+
+    /// Returns the index of the greatest element that is less than or equal to `target`, or `None` if there's no such element. If the slice is not sorted, the returned result is unspecified and meaningless.
+    ///
+    /// If there are multiple matches, the index of the last one is returned.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_floor(&13), Some(7));
+    /// assert_eq!(arr.interpolation_floor(&20), Some(7));
+    /// assert_eq!(arr.interpolation_floor(&1), Some(2));
+    /// assert_eq!(arr.interpolation_floor(&-1), None);
+    /// ```
+    fn interpolation_floor(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor;
+
+    /// Returns the index of the least element that is greater than or equal to `target`, or `None` if there's no such element. If the slice is not sorted, the returned result is unspecified and meaningless.
+    ///
+    /// If there are multiple matches, the index of the first one is returned.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_ceil(&13), Some(7));
+    /// assert_eq!(arr.interpolation_ceil(&14), Some(8));
+    /// assert_eq!(arr.interpolation_ceil(&1), Some(1));
+    /// assert_eq!(arr.interpolation_ceil(&56), None);
+    /// ```
+    fn interpolation_ceil(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor;
+
+    /// Returns the index of the element with the greatest key that is less than or equal to `target`, using a key extraction function. If the slice is not sorted by keys, the returned result is unspecified and meaningless.
+    ///
+    /// If there are multiple matches, the index of the last one is returned.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let symbols = [(0x1000, "_start"), (0x1040, "main"), (0x10c0, "helper")];
+    ///
+    /// assert_eq!(symbols.interpolation_floor_by_key(&0x1052, |(addr, _)| addr), Some(1));
+    /// assert_eq!(symbols.interpolation_floor_by_key(&0x0fff, |(addr, _)| addr), None);
+    /// ```
+    fn interpolation_floor_by_key<K, F>(&self, target: &K, f: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K;
+
+    /// Returns the index of the element with the least key that is greater than or equal to `target`, using a key extraction function. If the slice is not sorted by keys, the returned result is unspecified and meaningless.
+    ///
+    /// If there are multiple matches, the index of the first one is returned.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let levels = [(99, 300), (101, 150), (105, 20)];
+    ///
+    /// assert_eq!(levels.interpolation_ceil_by_key(&100, |(price, _)| price), Some(1));
+    /// assert_eq!(levels.interpolation_ceil_by_key(&106, |(price, _)| price), None);
+    /// ```
+    fn interpolation_ceil_by_key<K, F>(&self, target: &K, f: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K;
+
+    /// Returns the index of the element closest to `target` by [`Distance`], or `None` if the slice is empty. If the slice is not sorted, the returned result is unspecified and meaningless.
+    ///
+    /// If there are elements equal to `target`, the index of the first one is returned. Otherwise the closer of the neighbours of `target` is picked, and the smaller one if they're equally close. If a neighbour is repeated, the index of the occurrence next to `target` is returned.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_nearest(&12), Some(7));
+    /// assert_eq!(arr.interpolation_nearest(&10), Some(6));
+    /// assert_eq!(arr.interpolation_nearest(&1), Some(1));
+    /// assert_eq!(arr.interpolation_nearest(&100), Some(10));
+    /// ```
+    fn interpolation_nearest(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor + Distance;
+
+    /// Returns the index of the element with the key closest to `target` by [`Distance`], using a key extraction function. If the slice is not sorted by keys, the returned result is unspecified and meaningless.
+    ///
+    /// Ties are broken as in [`interpolation_nearest`](InterpolationSearch::interpolation_nearest).
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let samples = [(1000, 0.5), (1010, 0.7), (1020, 0.6)];
+    ///
+    /// assert_eq!(samples.interpolation_nearest_by_key(&1014, |(t, _)| t), Some(1));
+    /// assert_eq!(samples.interpolation_nearest_by_key(&1015, |(t, _)| t), Some(1));
+    /// assert_eq!(samples.interpolation_nearest_by_key(&1016, |(t, _)| t), Some(2));
+    /// ```
+    fn interpolation_nearest_by_key<K, F>(&self, target: &K, f: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
+        F: FnMut(&T) -> &K;
+
+    /// Returns the range of indices of the `k` elements closest to `target` by [`Distance`], or of all elements if there are fewer than `k`. If the slice is not sorted, the returned range is unspecified and meaningless.
+    ///
+    /// The window grows from the position of `target`, one element at a time towards the closer neighbour, and towards the smaller one if they're equally close.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_k_nearest(&6, 3), 4..7);
+    /// assert_eq!(arr.interpolation_k_nearest(&50, 2), 9..11);
+    /// assert_eq!(arr.interpolation_k_nearest(&0, 100), 0..11);
+    /// ```
+    fn interpolation_k_nearest(&self, target: &T, k: usize) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor + Distance;
+
+    /// Returns the range of indices of the `k` elements with keys closest to `target` by [`Distance`], using a key extraction function. If the slice is not sorted by keys, the returned range is unspecified and meaningless.
+    ///
+    /// The window grows as in [`interpolation_k_nearest`](InterpolationSearch::interpolation_k_nearest).
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationSearch;
+    ///
+    /// let samples = [(1000, 0.5), (1010, 0.7), (1020, 0.6), (1030, 0.4)];
+    ///
+    /// assert_eq!(samples.interpolation_k_nearest_by_key(&1012, 2, |(t, _)| t), 1..3);
+    /// ```
+    fn interpolation_k_nearest_by_key<K, F>(&self, target: &K, k: usize, f: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
         F: FnMut(&T) -> &K;
 }
 
@@ -60,7 +266,7 @@ impl<T> InterpolationSearch<T> for [T] {
 
     fn interpolation_search_by_key<K, F>(&self, target: &K, mut key: F) -> Result<usize, usize>
     where
-        K: Ord + InterpolationFactor,
+        K: Ord + InterpolationFactor + ?Sized,
         F: FnMut(&T) -> &K,
     {
         let mut first_idx = 0;
@@ -85,6 +291,417 @@ impl<T> InterpolationSearch<T> for [T] {
             }
         }
     }
+
+    fn interpolation_range<R>(&self, range: R) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor,
+        R: RangeBounds<T>,
+    {
+        self.interpolation_range_by_key(range, |x| x)
+    }
+
+    fn interpolation_range_by_key<K, R, F>(&self, range: R, mut key: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        R: RangeBounds<K>,
+        F: FnMut(&T) -> &K,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => lower_bound_by_key(self, start, &mut key),
+            Bound::Excluded(start) => upper_bound_by_key(self, start, &mut key),
+            Bound::Unbounded => 0,
+        };
+        // Everything before `start` is below the range, so the end is searched for after it.
+        let rest = &self[start..];
+        let len = match range.end_bound() {
+            Bound::Included(end) => upper_bound_by_key(rest, end, &mut key),
+            Bound::Excluded(end) => lower_bound_by_key(rest, end, &mut key),
+            Bound::Unbounded => rest.len(),
+        };
+        start..start + len
+    }
+
+    fn interpolation_floor(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor,
+    {
+        self.interpolation_floor_by_key(target, |x| x)
+    }
+
+    fn interpolation_ceil(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor,
+    {
+        self.interpolation_ceil_by_key(target, |x| x)
+    }
+
+    fn interpolation_floor_by_key<K, F>(&self, target: &K, key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        upper_bound_by_key(self, target, key).checked_sub(1)
+    }
+
+    fn interpolation_ceil_by_key<K, F>(&self, target: &K, key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        Some(lower_bound_by_key(self, target, key)).filter(|&idx| idx < self.len())
+    }
+
+    fn interpolation_nearest(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor + Distance,
+    {
+        self.interpolation_nearest_by_key(target, |x| x)
+    }
+
+    fn interpolation_nearest_by_key<K, F>(&self, target: &K, mut key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
+        F: FnMut(&T) -> &K,
+    {
+        let ceil = lower_bound_by_key(self, target, &mut key);
+        nearest_by_key(ceil, |idx| self.get(idx), target, key)
+    }
+
+    fn interpolation_k_nearest(&self, target: &T, k: usize) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor + Distance,
+    {
+        self.interpolation_k_nearest_by_key(target, k, |x| x)
+    }
+
+    fn interpolation_k_nearest_by_key<K, F>(&self, target: &K, k: usize, mut key: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
+        F: FnMut(&T) -> &K,
+    {
+        let mid = lower_bound_by_key(self, target, &mut key);
+        k_nearest_by_key(mid, self.len(), |idx| self.get(idx), target, k, key)
+    }
+}
+
+// A `VecDeque` is searched as one logical sequence made of its two segments, so indices are
+// logical ones, as for `VecDeque::get`. The back segment only needs to be searched if its first
+// element is before the target, and the front one otherwise.
+impl<T> InterpolationSearch<T> for VecDeque<T> {
+    fn interpolation_search(&self, target: &T) -> Result<usize, usize>
+    where
+        T: Ord + InterpolationFactor,
+    {
+        self.interpolation_search_by_key(target, |x| x)
+    }
+
+    fn interpolation_search_by_key<K, F>(&self, target: &K, mut key: F) -> Result<usize, usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        let (front, back) = self.as_slices();
+        match back.first() {
+            Some(first) if key(first) <= target => back
+                .interpolation_search_by_key(target, key)
+                .map(|idx| front.len() + idx)
+                .map_err(|idx| front.len() + idx),
+            _ => front.interpolation_search_by_key(target, key),
+        }
+    }
+
+    fn interpolation_range<R>(&self, range: R) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor,
+        R: RangeBounds<T>,
+    {
+        self.interpolation_range_by_key(range, |x| x)
+    }
+
+    fn interpolation_range_by_key<K, R, F>(&self, range: R, mut key: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        R: RangeBounds<K>,
+        F: FnMut(&T) -> &K,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => deque_lower_bound_by_key(self, 0, start, &mut key),
+            Bound::Excluded(start) => deque_upper_bound_by_key(self, 0, start, &mut key),
+            Bound::Unbounded => 0,
+        };
+        // Everything before `start` is below the range, so the end is searched for after it.
+        let end = match range.end_bound() {
+            Bound::Included(end) => deque_upper_bound_by_key(self, start, end, &mut key),
+            Bound::Excluded(end) => deque_lower_bound_by_key(self, start, end, &mut key),
+            Bound::Unbounded => self.len(),
+        };
+        start..end
+    }
+
+    fn interpolation_floor(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor,
+    {
+        self.interpolation_floor_by_key(target, |x| x)
+    }
+
+    fn interpolation_ceil(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor,
+    {
+        self.interpolation_ceil_by_key(target, |x| x)
+    }
+
+    fn interpolation_floor_by_key<K, F>(&self, target: &K, key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        deque_upper_bound_by_key(self, 0, target, key).checked_sub(1)
+    }
+
+    fn interpolation_ceil_by_key<K, F>(&self, target: &K, key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + ?Sized,
+        F: FnMut(&T) -> &K,
+    {
+        Some(deque_lower_bound_by_key(self, 0, target, key)).filter(|&idx| idx < self.len())
+    }
+
+    fn interpolation_nearest(&self, target: &T) -> Option<usize>
+    where
+        T: Ord + InterpolationFactor + Distance,
+    {
+        self.interpolation_nearest_by_key(target, |x| x)
+    }
+
+    fn interpolation_nearest_by_key<K, F>(&self, target: &K, mut key: F) -> Option<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
+        F: FnMut(&T) -> &K,
+    {
+        let ceil = deque_lower_bound_by_key(self, 0, target, &mut key);
+        nearest_by_key(ceil, |idx| self.get(idx), target, key)
+    }
+
+    fn interpolation_k_nearest(&self, target: &T, k: usize) -> Range<usize>
+    where
+        T: Ord + InterpolationFactor + Distance,
+    {
+        self.interpolation_k_nearest_by_key(target, k, |x| x)
+    }
+
+    fn interpolation_k_nearest_by_key<K, F>(&self, target: &K, k: usize, mut key: F) -> Range<usize>
+    where
+        K: Ord + InterpolationFactor + Distance,
+        F: FnMut(&T) -> &K,
+    {
+        let mid = deque_lower_bound_by_key(self, 0, target, &mut key);
+        k_nearest_by_key(mid, self.len(), |idx| self.get(idx), target, k, key)
+    }
+}
+
+/// Extends slices with a sub-slice counterpart of [`interpolation_range`](InterpolationSearch::interpolation_range).
+///
+/// It's only implemented for contiguous storage: the elements of a `VecDeque` may wrap around its buffer, so use `interpolation_range` with `VecDeque::range` there.
+pub trait InterpolationRangeSlice<T> {
+    /// Returns the sub-slice of the elements within `range`. If the slice is not sorted, the returned sub-slice is unspecified and meaningless.
+    ///
+    /// This is a convenience for indexing with [`interpolation_range`](InterpolationSearch::interpolation_range).
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::InterpolationRangeSlice;
+    ///
+    /// let arr = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+    ///
+    /// assert_eq!(arr.interpolation_range_slice(1..=3), [1, 1, 2, 3]);
+    /// assert!(arr.interpolation_range_slice(56..).is_empty());
+    /// ```
+    fn interpolation_range_slice<R>(&self, range: R) -> &[T]
+    where
+        T: Ord + InterpolationFactor,
+        R: RangeBounds<T>;
+}
+
+impl<T> InterpolationRangeSlice<T> for [T] {
+    fn interpolation_range_slice<R>(&self, range: R) -> &[T]
+    where
+        T: Ord + InterpolationFactor,
+        R: RangeBounds<T>,
+    {
+        &self[self.interpolation_range(range)]
+    }
+}
+
+mod private {
+    use std::collections::VecDeque;
+
+    pub trait Sealed {}
+
+    impl<T> Sealed for [T] {}
+    impl<T> Sealed for VecDeque<T> {}
+}
+
+// Returns the index of the element nearest to `target`, given the index `ceil` of the first element
+// with a key greater than or equal to it. Elements are fetched by index through `get`.
+fn nearest_by_key<'a, T, K, F, G>(ceil: usize, get: G, target: &K, mut key: F) -> Option<usize>
+where
+    T: 'a,
+    K: Ord + Distance,
+    F: FnMut(&T) -> &K,
+    G: Fn(usize) -> Option<&'a T>,
+{
+    match (ceil.checked_sub(1).and_then(&get), get(ceil)) {
+        (None, None) => None,
+        (Some(_), None) => Some(ceil - 1),
+        (None, Some(_)) => Some(ceil),
+        (Some(lower), Some(upper)) => {
+            let lower = key(lower).distance(target);
+            if lower <= key(upper).distance(target) && key(upper) != target {
+                Some(ceil - 1)
+            } else {
+                Some(ceil)
+            }
+        }
+    }
+}
+
+// Returns the range of the `k` elements nearest to `target` out of `len`, grown from the index `mid`
+// of the first element with a key greater than or equal to it. Elements are fetched by index
+// through `get`.
+fn k_nearest_by_key<'a, T, K, F, G>(
+    mid: usize,
+    len: usize,
+    get: G,
+    target: &K,
+    k: usize,
+    mut key: F,
+) -> Range<usize>
+where
+    T: 'a,
+    K: Ord + Distance,
+    F: FnMut(&T) -> &K,
+    G: Fn(usize) -> Option<&'a T>,
+{
+    let (mut start, mut end) = (mid, mid);
+    while end - start < k.min(len) {
+        let take_lower = match (start.checked_sub(1).and_then(&get), get(end)) {
+            (Some(lower), Some(upper)) => {
+                key(lower).distance(target) <= key(upper).distance(target)
+            }
+            (lower, _) => lower.is_some(),
+        };
+        if take_lower {
+            start -= 1;
+        } else {
+            end += 1;
+        }
+    }
+    start..end
+}
+
+// Returns the index of the first element with a key greater than or equal to `target`.
+pub(crate) fn lower_bound_by_key<T, K, F>(slice: &[T], target: &K, key: F) -> usize
+where
+    K: Ord + InterpolationFactor + ?Sized,
+    F: FnMut(&T) -> &K,
+{
+    partition_by_key(slice, target, key, |k| k < target)
+}
+
+// Returns the index of the first element with a key greater than `target`.
+pub(crate) fn upper_bound_by_key<T, K, F>(slice: &[T], target: &K, key: F) -> usize
+where
+    K: Ord + InterpolationFactor + ?Sized,
+    F: FnMut(&T) -> &K,
+{
+    partition_by_key(slice, target, key, |k| k <= target)
+}
+
+// Returns the logical index of the first element of `deque` from logical index `from` on with a
+// key greater than or equal to `target`.
+fn deque_lower_bound_by_key<T, K, F>(
+    deque: &VecDeque<T>,
+    from: usize,
+    target: &K,
+    mut key: F,
+) -> usize
+where
+    K: Ord + InterpolationFactor + ?Sized,
+    F: FnMut(&T) -> &K,
+{
+    let (front, back) = deque_slices_from(deque, from);
+    from + match back.first() {
+        Some(first) if key(first) < target => front.len() + lower_bound_by_key(back, target, key),
+        _ => lower_bound_by_key(front, target, key),
+    }
+}
+
+// Returns the logical index of the first element of `deque` from logical index `from` on with a
+// key greater than `target`.
+fn deque_upper_bound_by_key<T, K, F>(
+    deque: &VecDeque<T>,
+    from: usize,
+    target: &K,
+    mut key: F,
+) -> usize
+where
+    K: Ord + InterpolationFactor + ?Sized,
+    F: FnMut(&T) -> &K,
+{
+    let (front, back) = deque_slices_from(deque, from);
+    from + match back.first() {
+        Some(first) if key(first) <= target => front.len() + upper_bound_by_key(back, target, key),
+        _ => upper_bound_by_key(front, target, key),
+    }
+}
+
+// Returns the two segments of `deque` from logical index `from` on.
+fn deque_slices_from<T>(deque: &VecDeque<T>, from: usize) -> (&[T], &[T]) {
+    let (front, back) = deque.as_slices();
+    match from.checked_sub(front.len()) {
+        Some(skip) => (&back[skip..], &[]),
+        None => (&front[from..], back),
+    }
+}
+
+// Interpolation counterpart of `partition_point` for a predicate that compares keys with `target`.
+// Unlike a plain search, the partition point must be found even within a run of keys equal to the
+// target, so whenever an interpolation step fails to halve the range the next step bisects it.
+fn partition_by_key<T, K, F, P>(slice: &[T], target: &K, mut key: F, is_before: P) -> usize
+where
+    K: Ord + InterpolationFactor + ?Sized,
+    F: FnMut(&T) -> &K,
+    P: Fn(&K) -> bool,
+{
+    let mut first_idx = 0;
+    let mut last_idx = slice.len();
+    let mut bisect = false;
+    loop {
+        match &slice[first_idx..last_idx] {
+            [] => return first_idx,
+            [first, ..] if !is_before(key(first)) => return first_idx,
+            [.., last] if is_before(key(last)) => return last_idx,
+            [first, .., last] => {
+                let len = last_idx - first_idx;
+                let mid_idx = if bisect {
+                    first_idx + len / 2
+                } else {
+                    let f = target.interpolation_factor(key(first), key(last));
+                    lerp_idx(first_idx, last_idx, f)
+                };
+                if is_before(key(&slice[mid_idx])) {
+                    first_idx = mid_idx + 1;
+                } else {
+                    last_idx = mid_idx;
+                }
+                bisect = last_idx - first_idx > len / 2;
+            }
+            [_] => return first_idx, // Should not happen if the array is sorted
+        }
+    }
 }
 
 // Returns an index in a given inclusive-exclusive index range (`[first, last)`).
@@ -104,9 +721,19 @@ fn normalize(f: f32) -> f32 {
 }
 
 #[cfg(test)]
+// The oldest tests predate these lints and are kept as they were written.
+#[allow(clippy::neg_multiply, clippy::redundant_closure, clippy::useless_vec)]
 mod tests {
     use super::*;
-    use std::time::{Duration, SystemTime};
+    use std::{
+        cmp::Reverse,
+        net::IpAddr,
+        num::{NonZero, Saturating, Wrapping},
+        path::PathBuf,
+        rc::Rc,
+        sync::Arc,
+        time::{Duration, Instant, SystemTime},
+    };
 
     #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
     struct Item {
@@ -182,6 +809,67 @@ mod tests {
         assert_eq!(arr.interpolation_search(&0), Ok(0));
     }
 
+    #[test]
+    fn test_wrapper_types() {
+        let values = [0, 1, 2, 3, 5, 8, 13];
+
+        let arr = values.map(Reverse);
+        let mut arr = arr.to_vec();
+        arr.sort();
+        assert_eq!(arr.interpolation_search(&Reverse(13)), Ok(0));
+        assert_eq!(arr.interpolation_search(&Reverse(3)), Ok(3));
+        assert_eq!(arr.interpolation_search(&Reverse(0)), Ok(6));
+        assert_eq!(arr.interpolation_search(&Reverse(4)), Err(3));
+        assert_eq!(arr.interpolation_search(&Reverse(100)), Err(0));
+        assert_eq!(arr.interpolation_search(&Reverse(-1)), Err(7));
+
+        let arr = values.map(Wrapping);
+        assert_eq!(arr.interpolation_search(&Wrapping(8)), Ok(5));
+        let arr = values.map(Saturating);
+        assert_eq!(arr.interpolation_search(&Saturating(4)), Err(4));
+        let arr = values.map(|n| NonZero::new(n + 1).unwrap());
+        assert_eq!(arr.interpolation_search(&NonZero::new(14).unwrap()), Ok(6));
+
+        let arr = [None, None, Some(1), Some(2), Some(5)];
+        assert!(matches!(arr.interpolation_search(&None), Ok(0..=1)));
+        assert_eq!(arr.interpolation_search(&Some(2)), Ok(3));
+        assert_eq!(arr.interpolation_search(&Some(0)), Err(2));
+        assert_eq!(arr.interpolation_search(&Some(6)), Err(5));
+
+        let arr = [false, false, true, true, true];
+        assert!(matches!(arr.interpolation_search(&false), Ok(0..=1)));
+        assert!(matches!(arr.interpolation_search(&true), Ok(2..=4)));
+    }
+
+    #[test]
+    fn test_references_and_smart_pointers() {
+        let values = [0, 1, 2, 3, 5, 8, 13];
+
+        let arr = values.iter().collect::<Vec<&i32>>();
+        assert_eq!(arr.interpolation_search(&&5), Ok(4));
+        assert_eq!(arr.interpolation_search(&&4), Err(4));
+
+        let mut owned = values;
+        let arr = owned.iter_mut().collect::<Vec<&mut i32>>();
+        assert_eq!(arr.interpolation_search(&&mut 13), Ok(6));
+        assert_eq!(arr.interpolation_search(&&mut 14), Err(7));
+
+        let arr = values.map(Box::new);
+        assert_eq!(arr.interpolation_search(&Box::new(0)), Ok(0));
+        assert_eq!(arr.interpolation_search(&Box::new(-1)), Err(0));
+
+        let arr = values.map(Rc::new);
+        assert_eq!(arr.interpolation_search(&Rc::new(8)), Ok(5));
+        assert_eq!(arr.interpolation_search(&Rc::new(7)), Err(5));
+
+        let arr = values.map(Arc::new);
+        assert_eq!(arr.interpolation_search(&Arc::new(2)), Ok(2));
+        assert_eq!(arr.interpolation_search(&Arc::new(100)), Err(7));
+
+        let arr = ["apple", "banana", "cherry"].map(Box::<str>::from);
+        assert_eq!(arr.interpolation_search(&"banana".into()), Ok(1));
+    }
+
     #[test]
     fn test_time_points() {
         let t0 = SystemTime::now();
@@ -200,6 +888,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_durations() {
+        let arr = (0..10).map(Duration::from_millis).collect::<Vec<_>>();
+        assert_eq!(arr.interpolation_search(&Duration::ZERO), Ok(0));
+        assert_eq!(arr.interpolation_search(&Duration::from_millis(7)), Ok(7));
+        assert_eq!(
+            arr.interpolation_search(&Duration::from_micros(7500)),
+            Err(8)
+        );
+        assert_eq!(arr.interpolation_search(&Duration::from_secs(1)), Err(10));
+
+        let t0 = Instant::now();
+        let arr = arr.iter().map(|&delay| t0 + delay).collect::<Vec<_>>();
+        assert_eq!(arr.interpolation_search(&t0), Ok(0));
+        assert_eq!(
+            arr.interpolation_search(&(t0 + Duration::from_millis(5))),
+            Ok(5)
+        );
+        assert_eq!(
+            arr.interpolation_search(&(t0 + Duration::from_millis(15))),
+            Err(10)
+        );
+    }
+
+    #[test]
+    fn test_ip_addrs() {
+        let mut arr = [
+            "10.0.0.0",
+            "10.0.1.0",
+            "172.16.0.0",
+            "192.168.0.0",
+            "192.168.1.0",
+            "::1",
+            "2001:db8::",
+            "2001:db8::1:0",
+            "fe80::",
+        ]
+        .map(|ip| ip.parse::<IpAddr>().unwrap());
+        arr.sort();
+        for (idx, ip) in arr.iter().enumerate() {
+            assert_eq!(arr.interpolation_search(ip), Ok(idx));
+        }
+        for ip in [
+            "0.0.0.0",
+            "10.0.0.128",
+            "255.255.255.255",
+            "::",
+            "2001:db8::ff",
+            "ff00::",
+        ] {
+            let ip = ip.parse::<IpAddr>().unwrap();
+            assert_eq!(arr.interpolation_search(&ip), arr.binary_search(&ip));
+        }
+    }
+
     #[test]
     fn test_chars() {
         let arr = ('a'..='z').collect::<Vec<_>>();
@@ -215,6 +958,263 @@ mod tests {
         assert_eq!(arr.interpolation_search(&'Ա'), Err(0));
     }
 
+    #[test]
+    fn test_bounds() {
+        let arr = [1, 2, 3, 3, 4, 5, 6, 6, 6, 7, 8, 8, 8, 8, 9, 10];
+        for n in 0..=11 {
+            assert_eq!(
+                lower_bound_by_key(&arr, &n, |x| x),
+                arr.partition_point(|&x| x < n)
+            );
+            assert_eq!(
+                upper_bound_by_key(&arr, &n, |x| x),
+                arr.partition_point(|&x| x <= n)
+            );
+        }
+
+        let arr: [i32; 0] = [];
+        assert_eq!(lower_bound_by_key(&arr, &0, |x| x), 0);
+        assert_eq!(upper_bound_by_key(&arr, &0, |x| x), 0);
+
+        // Long runs of the target are bisected rather than walked.
+        let arr = std::iter::repeat_n(0, 1000)
+            .chain(std::iter::repeat_n(1, 100_000))
+            .collect::<Vec<_>>();
+        let mut probes = 0;
+        let lower = lower_bound_by_key(&arr, &1, |x| {
+            probes += 1;
+            x
+        });
+        assert_eq!(lower, 1000);
+        assert!(probes < 200);
+        assert_eq!(upper_bound_by_key(&arr, &0, |x| x), 1000);
+    }
+
+    #[test]
+    fn test_interpolation_range() {
+        let arr = [1, 2, 3, 3, 4, 5, 6, 6, 6, 7, 8, 8, 8, 8, 9, 10];
+        let position = |n: i32| arr.partition_point(|&x| x < n);
+        for lo in 0..=11 {
+            for hi in 0..=11 {
+                let (start, end) = (position(lo), position(hi));
+                assert_eq!(arr.interpolation_range(lo..hi), start..end.max(start));
+                let end = position(hi + 1);
+                assert_eq!(arr.interpolation_range(lo..=hi), start..end.max(start));
+                let start = position(lo + 1);
+                assert_eq!(
+                    arr.interpolation_range((Bound::Excluded(lo), Bound::Excluded(hi))),
+                    start..position(hi).max(start)
+                );
+            }
+            assert_eq!(arr.interpolation_range(lo..), position(lo)..arr.len());
+            assert_eq!(arr.interpolation_range(..lo), 0..position(lo));
+        }
+        assert_eq!(arr.interpolation_range(..), 0..arr.len());
+        assert_eq!(arr.interpolation_range_slice(6..=6), [6, 6, 6]);
+        assert_eq!(arr.interpolation_range_slice(11..), [] as [i32; 0]);
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.interpolation_range(1..5), 0..0);
+    }
+
+    #[test]
+    fn test_interpolation_range_by_key() {
+        let arr = (0..1000_u64)
+            .map(|n| (n * n, n.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            arr.interpolation_range_by_key(100..=400, |(k, _)| k),
+            10..21
+        );
+        assert_eq!(arr.interpolation_range_by_key(101..400, |(k, _)| k), 11..20);
+        let mut probes = 0;
+        let range = arr.interpolation_range_by_key(250_000..250_001, |(k, _)| {
+            probes += 1;
+            k
+        });
+        assert_eq!(range, 500..501);
+        // A few key extractions per step, far from a scan of the 500 elements before the range.
+        assert!(probes < 60, "{probes}");
+    }
+
+    #[test]
+    fn test_floor_and_ceil() {
+        let arr = [1, 2, 3, 3, 4, 5, 6, 6, 6, 7, 8, 8, 8, 8, 9, 10];
+        for n in 0..=11 {
+            let floor = arr.iter().rposition(|&x| x <= n);
+            let ceil = arr.iter().position(|&x| x >= n);
+            assert_eq!(arr.interpolation_floor(&n), floor, "{n}");
+            assert_eq!(arr.interpolation_ceil(&n), ceil, "{n}");
+        }
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.interpolation_floor(&0), None);
+        assert_eq!(arr.interpolation_ceil(&0), None);
+    }
+
+    #[test]
+    fn test_floor_and_ceil_by_key() {
+        let versions = [(1, "a"), (3, "b"), (3, "c"), (7, "d")];
+        let version = |n: u32| versions.interpolation_floor_by_key(&n, |(v, _)| v);
+        assert_eq!(version(0), None);
+        assert_eq!(version(3), Some(2));
+        assert_eq!(version(6), Some(2));
+        assert_eq!(version(100), Some(3));
+        let next = |n: u32| versions.interpolation_ceil_by_key(&n, |(v, _)| v);
+        assert_eq!(next(0), Some(0));
+        assert_eq!(next(2), Some(1));
+        assert_eq!(next(7), Some(3));
+        assert_eq!(next(8), None);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let arr = [1, 2, 3, 3, 4, 5, 6, 6, 6, 7, 8, 8, 8, 8, 9, 10, 20, 30];
+        for n in -5..=40 {
+            let idx = arr.interpolation_nearest(&n).unwrap();
+            let best = arr.iter().map(|x| x.distance(&n)).min().unwrap();
+            assert_eq!(arr[idx].distance(&n), best, "{n}");
+        }
+        // Exact matches return the first occurrence, ties the smaller neighbour next to the target.
+        assert_eq!(arr.interpolation_nearest(&8), Some(10));
+        assert_eq!(arr.interpolation_nearest(&15), Some(15));
+        assert_eq!(arr.interpolation_nearest(&16), Some(16));
+        assert_eq!(arr.interpolation_nearest(&25), Some(16));
+        assert_eq!(arr.interpolation_nearest(&0), Some(0));
+        assert_eq!(arr.interpolation_nearest(&100), Some(17));
+
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.interpolation_nearest(&0), None);
+    }
+
+    #[test]
+    fn test_nearest_by_key() {
+        let t0 = Instant::now();
+        let samples = (0..100_u64)
+            .map(|n| (t0 + Duration::from_millis(n * n), n))
+            .collect::<Vec<_>>();
+        let at = |ms| t0 + Duration::from_millis(ms);
+        let nearest = |ms| samples.interpolation_nearest_by_key(&at(ms), |(t, _)| t);
+        assert_eq!(nearest(0), Some(0));
+        assert_eq!(nearest(2500), Some(50));
+        assert_eq!(nearest(2540), Some(50));
+        assert_eq!(nearest(2560), Some(51));
+        assert_eq!(nearest(1_000_000), Some(99));
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let arr = [1, 2, 4, 8, 16, 32];
+        assert_eq!(arr.interpolation_k_nearest(&5, 0), 3..3);
+        assert_eq!(arr.interpolation_k_nearest(&5, 1), 2..3);
+        assert_eq!(arr.interpolation_k_nearest(&5, 2), 1..3);
+        assert_eq!(arr.interpolation_k_nearest(&5, 4), 0..4);
+        assert_eq!(arr.interpolation_k_nearest(&3, 2), 1..3);
+        assert_eq!(arr.interpolation_k_nearest(&0, 3), 0..3);
+        assert_eq!(arr.interpolation_k_nearest(&100, 3), 3..6);
+        assert_eq!(arr.interpolation_k_nearest(&100, 10), 0..6);
+
+        let arr = (0..1000).map(|n| n * 3).collect::<Vec<u32>>();
+        for target in (0..3100).step_by(7) {
+            let window = arr.interpolation_k_nearest(&target, 10);
+            assert_eq!(window.len(), 10);
+            let farthest = arr[window.clone()]
+                .iter()
+                .map(|x| x.distance(&target))
+                .max();
+            let outside = arr[..window.start]
+                .iter()
+                .chain(&arr[window.end..])
+                .map(|x| x.distance(&target))
+                .min();
+            assert!(outside.is_none_or(|outside| farthest <= Some(outside)));
+        }
+
+        let pairs = [(1, 'a'), (5, 'b'), (6, 'c')];
+        assert_eq!(
+            pairs.interpolation_k_nearest_by_key(&4, 2, |(n, _)| n),
+            1..3
+        );
+    }
+
+    #[test]
+    fn test_vec_deque() {
+        let arr = (0..300_u32).map(|n| n / 3 * 7).collect::<Vec<_>>();
+        for split in [0, 1, 2, 150, 299, 300] {
+            // Pushing the first elements to the front wraps them around the buffer.
+            let mut deque = VecDeque::with_capacity(arr.len());
+            deque.extend(&arr[split..]);
+            for &x in arr[..split].iter().rev() {
+                deque.push_front(x);
+            }
+            assert!(deque.iter().eq(&arr));
+            for from in [0, split, arr.len()] {
+                let (front, back) = deque_slices_from(&deque, from);
+                assert!(front.iter().chain(back).eq(&arr[from..]));
+            }
+            assert_eq!(
+                deque.as_slices().1.is_empty(),
+                split == 0 || split == arr.len()
+            );
+
+            for target in 0..710 {
+                match deque.interpolation_search(&target) {
+                    Ok(idx) => assert_eq!(deque[idx], target),
+                    Err(idx) => assert_eq!(arr.binary_search(&target), Err(idx)),
+                }
+                assert_eq!(
+                    deque.interpolation_range(target..target + 10),
+                    arr.interpolation_range(target..target + 10)
+                );
+                assert_eq!(
+                    deque.interpolation_range((Bound::Excluded(target), Bound::Included(target))),
+                    arr.interpolation_range((Bound::Excluded(target), Bound::Included(target)))
+                );
+                let inverted = Range {
+                    start: target + 10,
+                    end: target,
+                };
+                assert_eq!(
+                    deque.interpolation_range(inverted.clone()),
+                    arr.interpolation_range(inverted)
+                );
+                assert_eq!(
+                    deque.interpolation_floor(&target),
+                    arr.interpolation_floor(&target)
+                );
+                assert_eq!(
+                    deque.interpolation_ceil(&target),
+                    arr.interpolation_ceil(&target)
+                );
+                assert_eq!(
+                    deque.interpolation_nearest(&target),
+                    arr.interpolation_nearest(&target)
+                );
+                assert_eq!(
+                    deque.interpolation_k_nearest(&target, 5),
+                    arr.interpolation_k_nearest(&target, 5)
+                );
+            }
+        }
+
+        let mut samples = VecDeque::from([(20, 'c'), (30, 'd')]);
+        samples.push_front((10, 'b'));
+        samples.push_front((0, 'a'));
+        assert_eq!(samples.interpolation_search_by_key(&30, |(t, _)| t), Ok(3));
+        assert_eq!(samples.interpolation_range_by_key(5..=20, |(t, _)| t), 1..3);
+        assert_eq!(samples.interpolation_floor_by_key(&15, |(t, _)| t), Some(1));
+        assert_eq!(samples.interpolation_ceil_by_key(&15, |(t, _)| t), Some(2));
+        assert_eq!(
+            samples.interpolation_nearest_by_key(&26, |(t, _)| t),
+            Some(3)
+        );
+        assert_eq!(
+            samples.interpolation_k_nearest_by_key(&14, 2, |(t, _)| t),
+            1..3
+        );
+        assert_eq!(VecDeque::<u32>::new().interpolation_nearest(&1), None);
+    }
+
     #[test]
     fn test_normalize() {
         assert_eq!(normalize(0.0), 0.0);
@@ -289,6 +1289,38 @@ mod tests {
             .is_ok_and(|n| n < 3));
     }
 
+    #[test]
+    fn test_str_like_interpolation_search() {
+        let strings: Vec<Arc<str>> = ["apple", "banana", "cherry", "date", "elderberry"]
+            .into_iter()
+            .map(Arc::from)
+            .collect();
+        assert_eq!(strings.interpolation_search(&"apple".into()), Ok(0));
+        assert_eq!(strings.interpolation_search(&"date".into()), Ok(3));
+        assert_eq!(strings.interpolation_search(&"grape".into()), Err(5));
+        assert_eq!(strings.interpolation_search(&"bat".into()), Err(2));
+
+        let mut paths = [
+            "/usr/lib",
+            "/usr/bin",
+            "/etc",
+            "/usr/lib/x86_64",
+            "/usr-local",
+            "/var",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+        paths.sort();
+        for (idx, path) in paths.iter().enumerate() {
+            assert_eq!(paths.interpolation_search(path), Ok(idx));
+        }
+        assert_eq!(
+            paths.interpolation_search(&PathBuf::from("/usr/share")),
+            paths.binary_search(&PathBuf::from("/usr/share"))
+        );
+    }
+
     #[test]
     fn test_interpolation_search_by_key_tuple() {
         // Sorted by the second element (i32)
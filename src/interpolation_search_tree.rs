@@ -0,0 +1,283 @@
+use crate::InterpolationFactor;
+use std::ops::{Bound, Range, RangeBounds};
+
+// Ranges of at most this many elements are searched directly instead of being split further.
+const LEAF_SIZE: usize = 16;
+
+/// An immutable Interpolation Search Tree (IST).
+///
+/// The tree is built over a sorted `Vec<T>`. Every node of the tree covers a range of `n` elements
+/// and keeps a sample of about `sqrt(n)` of them, the representatives, which split the range into
+/// subtrees of about `sqrt(n)` elements each. An ID array divides the interpolation factor range
+/// `[0.0, 1.0]` of the node into equal parts and maps every part to the first representative in
+/// it, so the representative next to the target is found with a single interpolation and a short
+/// scan.
+///
+/// Lookups take *O(log log N)* expected time for the uniform distribution, like plain
+/// interpolation search, but the tree keeps that bound for a much broader class of distributions:
+/// every node only interpolates between its own representatives, so skewed keys degrade the
+/// search of a single node rather than of the whole slice. The worst case is *O(sqrt(N))*.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::InterpolationSearchTree;
+///
+/// let tree = InterpolationSearchTree::new((0..1000_u64).map(|n| n * n).collect());
+///
+/// assert_eq!(tree.get(&(500 * 500)), Some(&(500 * 500)));
+/// assert!(!tree.contains(&3));
+/// assert_eq!(tree.lower_bound(&3), 2);
+/// assert_eq!(tree.range(10..=100), [16, 25, 36, 49, 64, 81, 100]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct InterpolationSearchTree<T> {
+    items: Vec<T>,
+    root: Node,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    // Indices of the representatives in `items`.
+    reps: Vec<usize>,
+    // `ids[j]` is the number of representatives with an interpolation factor less than
+    // `j / ids.len()` between the first and the last element of the node.
+    ids: Vec<usize>,
+    // The subtrees between the representatives, one more than there are representatives.
+    children: Vec<Node>,
+}
+
+impl<T> InterpolationSearchTree<T>
+where
+    T: Ord + InterpolationFactor,
+{
+    /// Builds the tree over the sorted `items`. If `items` is not sorted, the results of the
+    /// queries are unspecified and meaningless.
+    pub fn new(items: Vec<T>) -> Self {
+        let root = Node::build(&items, 0..items.len());
+        Self { items, root }
+    }
+
+    /// Returns the number of elements in the tree.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the sorted elements of the tree.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consumes the tree, returning its sorted elements.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Returns a reference to an element equal to `target`, if there's any. If there are
+    /// multiple matches, the first one is returned.
+    pub fn get(&self, target: &T) -> Option<&T> {
+        self.items
+            .get(self.lower_bound(target))
+            .filter(|item| *item == target)
+    }
+
+    /// Returns `true` if the tree contains an element equal to `target`.
+    pub fn contains(&self, target: &T) -> bool {
+        self.get(target).is_some()
+    }
+
+    /// Returns the index of the first element that is greater than or equal to `target`, or the
+    /// number of elements if there's no such element.
+    pub fn lower_bound(&self, target: &T) -> usize {
+        self.partition_point(target, |item| item < target)
+    }
+
+    /// Returns the index of the first element that is greater than `target`, or the number of
+    /// elements if there's no such element.
+    pub fn upper_bound(&self, target: &T) -> usize {
+        self.partition_point(target, |item| item <= target)
+    }
+
+    /// Returns the sorted sub-slice of elements within `range`.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.lower_bound(start),
+            Bound::Excluded(start) => self.upper_bound(start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.upper_bound(end),
+            Bound::Excluded(end) => self.lower_bound(end),
+            Bound::Unbounded => self.items.len(),
+        };
+        &self.items[start..end.max(start)]
+    }
+
+    // Returns the index of the first element for which `is_before` is false. `target` is the
+    // boundary value of `is_before`, used to interpolate.
+    fn partition_point<F>(&self, target: &T, is_before: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut node = &self.root;
+        let mut range = 0..self.items.len();
+        while !node.reps.is_empty() {
+            let (first, last) = (&self.items[range.start], &self.items[range.end - 1]);
+            let f = target.interpolation_factor(first, last);
+            let j = ((f.clamp(0.0, 1.0) * node.ids.len() as f32) as usize).min(node.ids.len() - 1);
+            // Number of representatives before the target, starting from the interpolated guess.
+            let mut c = node.ids[j];
+            while c > 0 && !is_before(&self.items[node.reps[c - 1]]) {
+                c -= 1;
+            }
+            while c < node.reps.len() && is_before(&self.items[node.reps[c]]) {
+                c += 1;
+            }
+            range = Node::child_range(&node.reps, &range, c);
+            node = &node.children[c];
+        }
+        range.start + self.items[range].partition_point(is_before)
+    }
+}
+
+impl Node {
+    fn leaf() -> Self {
+        Self {
+            reps: Vec::new(),
+            ids: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn build<T: InterpolationFactor>(items: &[T], range: Range<usize>) -> Self {
+        let n = range.len();
+        if n <= LEAF_SIZE {
+            return Self::leaf();
+        }
+        let k = n.isqrt();
+        let reps = (1..=k)
+            .map(|i| range.start + i * n / (k + 1))
+            .collect::<Vec<_>>();
+        let (first, last) = (&items[range.start], &items[range.end - 1]);
+        let factors = reps
+            .iter()
+            .map(|&rep| items[rep].interpolation_factor(first, last))
+            .collect::<Vec<_>>();
+        let ids = (0..k)
+            .map(|j| factors.partition_point(|&f| f * (k as f32) < j as f32))
+            .collect();
+        let children = (0..=k)
+            .map(|c| Self::build(items, Self::child_range(&reps, &range, c)))
+            .collect();
+        Self {
+            reps,
+            ids,
+            children,
+        }
+    }
+
+    // Returns the range of the `c`-th subtree of a node covering `range`.
+    fn child_range(reps: &[usize], range: &Range<usize>, c: usize) -> Range<usize> {
+        let start = c.checked_sub(1).map_or(range.start, |prev| reps[prev] + 1);
+        let end = reps.get(c).copied().unwrap_or(range.end);
+        start..end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_against_slice(items: Vec<u64>, targets: impl Iterator<Item = u64>) {
+        let tree = InterpolationSearchTree::new(items.clone());
+        assert_eq!(tree.len(), items.len());
+        for target in targets {
+            let lower = items.partition_point(|&x| x < target);
+            let upper = items.partition_point(|&x| x <= target);
+            assert_eq!(tree.lower_bound(&target), lower, "{target}");
+            assert_eq!(tree.upper_bound(&target), upper, "{target}");
+            assert_eq!(tree.contains(&target), lower < upper);
+            assert_eq!(tree.get(&target), (lower < upper).then_some(&target));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let tree = InterpolationSearchTree::<u32>::new(Vec::new());
+        assert!(tree.is_empty());
+        assert_eq!(tree.get(&1), None);
+        assert_eq!(tree.lower_bound(&1), 0);
+        assert_eq!(tree.range(..), [0_u32; 0]);
+    }
+
+    #[test]
+    fn test_small() {
+        assert_against_slice(vec![5], 0..10);
+        assert_against_slice((0..16).map(|n| n * 2).collect(), 0..40);
+        assert_against_slice((0..17).map(|n| n * 2).collect(), 0..40);
+    }
+
+    #[test]
+    fn test_uniform() {
+        let items = (0..10_000).map(|n| n * 3).collect::<Vec<_>>();
+        assert_against_slice(items, 0..30_010);
+    }
+
+    #[test]
+    fn test_skewed() {
+        let mut items = (0..50).map(|n| 1_u64 << n).collect::<Vec<_>>();
+        items.extend((0..5000).map(|n| n * n * n));
+        items.sort();
+        let targets = items
+            .clone()
+            .into_iter()
+            .flat_map(|n| [n.saturating_sub(1), n, n + 1]);
+        assert_against_slice(items, targets);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let items = (0..1000_u64)
+            .flat_map(|n| std::iter::repeat_n(n * 10, (n % 40) as usize + 1))
+            .collect::<Vec<_>>();
+        assert_against_slice(items, 0..10_010);
+    }
+
+    #[test]
+    fn test_range() {
+        let tree = InterpolationSearchTree::new((0..100).map(|n| n * 10).collect());
+        assert_eq!(tree.range(15..45), [20, 30, 40]);
+        assert_eq!(tree.range(20..40), [20, 30]);
+        assert_eq!(tree.range(20..=40), [20, 30, 40]);
+        assert_eq!(
+            tree.range((Bound::Excluded(20), Bound::Included(40))),
+            [30, 40]
+        );
+        assert_eq!(tree.range(..25), [0, 10, 20]);
+        assert_eq!(tree.range(975..), [980, 990]);
+        assert_eq!(tree.range(..).len(), 100);
+        assert!(tree
+            .range((Bound::Included(40), Bound::Excluded(20)))
+            .is_empty());
+        assert!(tree.range(2000..).is_empty());
+    }
+
+    #[test]
+    fn test_strings() {
+        let mut items = (0..2000_u32)
+            .map(|n| format!("{:x}", n * 7919))
+            .collect::<Vec<_>>();
+        items.sort();
+        let tree = InterpolationSearchTree::new(items.clone());
+        for (idx, item) in items.iter().enumerate() {
+            assert_eq!(tree.lower_bound(item), idx);
+        }
+        assert!(!tree.contains(&"xyz".to_string()));
+        assert_eq!(tree.into_vec(), items);
+    }
+}
@@ -0,0 +1,479 @@
+use crate::{InterpolationFactor, InterpolationSearch};
+use std::{
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    mem,
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::{
+        Bound::{self, Excluded, Included},
+        Range, RangeInclusive,
+    },
+};
+
+/// A table of sorted, non-overlapping intervals with a value each, such as IP blocks, memory maps
+/// or time zone transitions.
+///
+/// The intervals are either all half-open [`Range`]s or all [`RangeInclusive`]s. Only inclusive
+/// intervals can end at the greatest key, e.g. to cover the last IPv4 block or `u64::MAX`. Their
+/// keys must implement [`Successor`], to tell whether there are keys between two intervals.
+///
+/// The interval containing a key is found by interpolation search on the starts of the intervals.
+/// Intervals may leave gaps between each other, which contain no keys.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::IntervalTable;
+/// use std::ops::Bound::{Excluded, Included};
+///
+/// let mut table = IntervalTable::new(vec![
+///     (0x2000..0x3000, "heap"),
+///     (0x1000..0x2000, "text"),
+///     (0x8000..0x9000, "stack"),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(table.get(&0x1234), Some(&"text"));
+/// assert_eq!(table.get(&0x4000), None);
+/// assert_eq!(
+///     table.gaps().collect::<Vec<_>>(),
+///     [(Included(&0x3000), Excluded(&0x8000))]
+/// );
+///
+/// assert!(IntervalTable::new(vec![(0..10, 'a'), (5..15, 'b')]).is_err());
+///
+/// let mut table = IntervalTable::new(vec![(0..=99_u8, 'a'), (100..=199, 'a'), (210..=255, 'b')])
+///     .unwrap();
+/// assert_eq!(table.get(&255), Some(&'b'));
+/// assert_eq!(
+///     table.gaps().collect::<Vec<_>>(),
+///     [(Excluded(&199), Excluded(&210))]
+/// );
+/// table.coalesce();
+/// assert_eq!(table.into_vec(), [(0..=199, 'a'), (210..=255, 'b')]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalTable<K, V, R = Range<K>> {
+    entries: Vec<(R, V)>,
+    key: PhantomData<K>,
+}
+
+/// The error returned when the intervals of an [`IntervalTable`] are invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalError<R> {
+    /// An interval that doesn't contain any keys, because its end is before its start, or not
+    /// after it for a half-open interval.
+    Empty(R),
+    /// Two intervals that share some keys.
+    Overlap(R, R),
+}
+
+/// An interval of keys that can be stored in an [`IntervalTable`]: a [`Range`] or a
+/// [`RangeInclusive`].
+///
+/// The trait is sealed, so it can't be implemented outside of this crate.
+pub trait Interval<K>: private::Sealed {
+    /// Returns the first key of the interval.
+    fn start(&self) -> &K;
+
+    /// Returns `true` if every key of the interval is less than `key`.
+    fn ends_before(&self, key: &K) -> bool;
+
+    /// Returns `true` if the interval contains no keys.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the keys between the interval and `next`, which starts after it, or `None` if
+    /// there are none.
+    fn gap_to<'a>(&'a self, next: &'a Self) -> Option<(Bound<&'a K>, Bound<&'a K>)>;
+
+    /// Joins the interval with `next`, which starts after it, into one interval from the start of
+    /// the former to the end of the latter.
+    fn join(self, next: Self) -> Self;
+}
+
+/// Keys with a successor, which [`IntervalTable`] needs for the gaps between inclusive intervals.
+pub trait Successor: Ord + Sized {
+    /// Returns the least key greater than `self`, or `None` if `self` is the greatest key.
+    fn successor(&self) -> Option<Self>;
+}
+
+macro_rules! integer_successor {
+    ($($t:ty),*) => {
+        $(
+            impl Successor for $t {
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+integer_successor!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Successor for char {
+    fn successor(&self) -> Option<Self> {
+        // Surrogates are skipped, as they're not characters.
+        (u32::from(*self) + 1..=u32::from(char::MAX)).find_map(char::from_u32)
+    }
+}
+
+impl Successor for Ipv4Addr {
+    fn successor(&self) -> Option<Self> {
+        u32::from(*self).checked_add(1).map(Self::from)
+    }
+}
+
+impl Successor for Ipv6Addr {
+    fn successor(&self) -> Option<Self> {
+        u128::from(*self).checked_add(1).map(Self::from)
+    }
+}
+
+impl<K: Ord> Interval<K> for Range<K> {
+    fn start(&self) -> &K {
+        &self.start
+    }
+
+    fn ends_before(&self, key: &K) -> bool {
+        self.end <= *key
+    }
+
+    fn is_empty(&self) -> bool {
+        Range::is_empty(self)
+    }
+
+    fn gap_to<'a>(&'a self, next: &'a Self) -> Option<(Bound<&'a K>, Bound<&'a K>)> {
+        (self.end < next.start).then_some((Included(&self.end), Excluded(&next.start)))
+    }
+
+    fn join(self, next: Self) -> Self {
+        self.start..next.end
+    }
+}
+
+impl<K: Successor> Interval<K> for RangeInclusive<K> {
+    fn start(&self) -> &K {
+        RangeInclusive::start(self)
+    }
+
+    fn ends_before(&self, key: &K) -> bool {
+        self.end() < key
+    }
+
+    fn is_empty(&self) -> bool {
+        RangeInclusive::is_empty(self)
+    }
+
+    fn gap_to<'a>(&'a self, next: &'a Self) -> Option<(Bound<&'a K>, Bound<&'a K>)> {
+        let after = self.end().successor()?;
+        (after < *next.start()).then_some((Excluded(self.end()), Excluded(next.start())))
+    }
+
+    fn join(self, next: Self) -> Self {
+        let (start, _) = self.into_inner();
+        let (_, end) = next.into_inner();
+        start..=end
+    }
+}
+
+mod private {
+    use std::ops::{Range, RangeInclusive};
+
+    pub trait Sealed {}
+
+    impl<K> Sealed for Range<K> {}
+    impl<K> Sealed for RangeInclusive<K> {}
+}
+
+impl<K, V, R> IntervalTable<K, V, R>
+where
+    K: Ord + InterpolationFactor,
+    R: Interval<K>,
+{
+    /// Creates a table from `intervals`, sorting them by their starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an interval is empty, or if two intervals overlap.
+    pub fn new(mut intervals: Vec<(R, V)>) -> Result<Self, IntervalError<R>>
+    where
+        R: Clone,
+    {
+        if let Some((range, _)) = intervals.iter().find(|(range, _)| range.is_empty()) {
+            return Err(IntervalError::Empty(range.clone()));
+        }
+        intervals.sort_by(|(a, _), (b, _)| a.start().cmp(b.start()));
+        if let Some(pair) = intervals
+            .windows(2)
+            .find(|pair| !pair[0].0.ends_before(pair[1].0.start()))
+        {
+            return Err(IntervalError::Overlap(pair[0].0.clone(), pair[1].0.clone()));
+        }
+        Ok(Self {
+            entries: intervals,
+            key: PhantomData,
+        })
+    }
+
+    /// Returns the number of intervals in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table has no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the intervals and their values, sorted by their starts.
+    pub fn as_slice(&self) -> &[(R, V)] {
+        &self.entries
+    }
+
+    /// Consumes the table, returning the intervals and their values sorted by their starts.
+    pub fn into_vec(self) -> Vec<(R, V)> {
+        self.entries
+    }
+
+    /// Returns an iterator over the intervals and their values, sorted by their starts.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&R, &V)> + ExactSizeIterator {
+        self.entries.iter().map(|(range, value)| (range, value))
+    }
+
+    /// Returns the index of the interval that contains `key`, if there's any.
+    pub fn find(&self, key: &K) -> Option<usize> {
+        let idx = self
+            .entries
+            .interpolation_floor_by_key(key, |(range, _)| range.start())?;
+        (!self.entries[idx].0.ends_before(key)).then_some(idx)
+    }
+
+    /// Returns the value of the interval that contains `key`, if there's any.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|idx| &self.entries[idx].1)
+    }
+
+    /// Returns the interval that contains `key` and its value, if there's any.
+    pub fn get_key_value(&self, key: &K) -> Option<(&R, &V)> {
+        let (range, value) = &self.entries[self.find(key)?];
+        Some((range, value))
+    }
+
+    /// Returns a mutable reference to the value of the interval that contains `key`, if there's
+    /// any.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let idx = self.find(key)?;
+        Some(&mut self.entries[idx].1)
+    }
+
+    /// Returns an iterator over the gaps between the intervals, in sorted order, as pairs of
+    /// bounds. The keys before the first and after the last interval are not considered gaps.
+    pub fn gaps(&self) -> impl Iterator<Item = (Bound<&K>, Bound<&K>)> {
+        self.entries
+            .windows(2)
+            .filter_map(|pair| pair[0].0.gap_to(&pair[1].0))
+    }
+
+    /// Merges adjacent intervals with equal values. Intervals are adjacent if there are no keys
+    /// between them.
+    pub fn coalesce(&mut self)
+    where
+        V: PartialEq,
+    {
+        let mut entries: Vec<(R, V)> = Vec::with_capacity(self.entries.len());
+        for (range, value) in mem::take(&mut self.entries) {
+            match entries.pop() {
+                Some((prev, prev_value))
+                    if prev_value == value && prev.gap_to(&range).is_none() =>
+                {
+                    entries.push((prev.join(range), value));
+                }
+                Some(prev) => entries.extend([prev, (range, value)]),
+                None => entries.push((range, value)),
+            }
+        }
+        self.entries = entries;
+    }
+}
+
+impl<R: fmt::Debug> fmt::Display for IntervalError<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalError::Empty(range) => write!(f, "interval {range:?} is empty"),
+            IntervalError::Overlap(a, b) => write!(f, "intervals {a:?} and {b:?} overlap"),
+        }
+    }
+}
+
+impl<R: fmt::Debug> Error for IntervalError<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let table = IntervalTable::<u32, ()>::new(Vec::new()).unwrap();
+        assert!(table.is_empty());
+        assert_eq!(table.get(&0), None);
+        assert_eq!(table.gaps().count(), 0);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            IntervalTable::new(vec![(0..5, 'a'), (5..5, 'b')]),
+            Err(IntervalError::Empty(5..5))
+        );
+        let inverted = Range { start: 7, end: 3 };
+        assert_eq!(
+            IntervalTable::new(vec![(inverted.clone(), 'a')]),
+            Err(IntervalError::Empty(inverted))
+        );
+        let err = IntervalTable::new(vec![(10..20, 'a'), (0..5, 'b'), (15..30, 'c')]).unwrap_err();
+        assert_eq!(err, IntervalError::Overlap(10..20, 15..30));
+        assert_eq!(err.to_string(), "intervals 10..20 and 15..30 overlap");
+        assert!(IntervalTable::new(vec![(10..20, 'a'), (0..10, 'b'), (20..30, 'c')]).is_ok());
+    }
+
+    #[test]
+    fn test_lookup() {
+        let intervals = (0..1000_u32)
+            .map(|n| (n * 10..n * 10 + 7, n))
+            .collect::<Vec<_>>();
+        let mut table = IntervalTable::new(intervals).unwrap();
+        for key in 0..10_010 {
+            let expected = (key % 10 < 7 && key < 10_000).then_some(key / 10);
+            assert_eq!(table.get(&key).copied(), expected, "{key}");
+        }
+        assert_eq!(table.get_key_value(&123), Some((&(120..127), &12)));
+        *table.get_mut(&5).unwrap() = 42;
+        assert_eq!(table.iter().next(), Some((&(0..7), &42)));
+        assert_eq!(table.gaps().count(), 999);
+        assert_eq!(table.gaps().next(), Some((Included(&7), Excluded(&10))));
+    }
+
+    #[test]
+    fn test_ip_blocks() {
+        let block = |a, b| Ipv4Addr::new(a, b, 0, 0)..=Ipv4Addr::new(a, b, 255, 255);
+        let table = IntervalTable::new(vec![
+            (block(10, 0), "office"),
+            (block(10, 1), "lab"),
+            (block(192, 168), "home"),
+            (block(255, 255), "broadcast"),
+        ])
+        .unwrap();
+        assert_eq!(table.get(&Ipv4Addr::new(10, 1, 2, 3)), Some(&"lab"));
+        assert_eq!(table.get(&Ipv4Addr::new(10, 1, 255, 255)), Some(&"lab"));
+        assert_eq!(table.get(&Ipv4Addr::new(10, 2, 0, 0)), None);
+        assert_eq!(table.get(&Ipv4Addr::new(192, 168, 255, 255)), Some(&"home"));
+        assert_eq!(table.get(&Ipv4Addr::BROADCAST), Some(&"broadcast"));
+        assert_eq!(table.get(&Ipv4Addr::new(255, 254, 255, 255)), None);
+        assert_eq!(
+            table.gaps().collect::<Vec<_>>(),
+            [
+                (
+                    Excluded(&Ipv4Addr::new(10, 1, 255, 255)),
+                    Excluded(&Ipv4Addr::new(192, 168, 0, 0))
+                ),
+                (
+                    Excluded(&Ipv4Addr::new(192, 168, 255, 255)),
+                    Excluded(&Ipv4Addr::new(255, 255, 0, 0))
+                ),
+            ]
+        );
+
+        let mut table = IntervalTable::new(vec![
+            (block(10, 0), "private"),
+            (block(10, 1), "private"),
+            (block(10, 3), "private"),
+            (block(255, 254), "reserved"),
+            (block(255, 255), "reserved"),
+        ])
+        .unwrap();
+        table.coalesce();
+        assert_eq!(
+            table.into_vec(),
+            [
+                (
+                    Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 1, 255, 255),
+                    "private"
+                ),
+                (block(10, 3), "private"),
+                (
+                    Ipv4Addr::new(255, 254, 0, 0)..=Ipv4Addr::BROADCAST,
+                    "reserved"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_successor() {
+        assert_eq!(7_u8.successor(), Some(8));
+        assert_eq!(u8::MAX.successor(), None);
+        assert_eq!((-1_i32).successor(), Some(0));
+        assert_eq!('a'.successor(), Some('b'));
+        assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+        assert_eq!(char::MAX.successor(), None);
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 255, 255).successor(),
+            Some(Ipv4Addr::new(10, 1, 0, 0))
+        );
+        assert_eq!(Ipv4Addr::BROADCAST.successor(), None);
+        assert_eq!(Ipv6Addr::UNSPECIFIED.successor(), Some(Ipv6Addr::LOCALHOST));
+    }
+
+    #[test]
+    fn test_inclusive() {
+        let table = IntervalTable::new(vec![
+            (u64::MAX - 9..=u64::MAX, 'z'),
+            (0..=9, 'a'),
+            (10..=10, 'b'),
+        ])
+        .unwrap();
+        assert_eq!(table.get(&0), Some(&'a'));
+        assert_eq!(table.get(&9), Some(&'a'));
+        assert_eq!(table.get(&10), Some(&'b'));
+        assert_eq!(table.get(&11), None);
+        assert_eq!(table.get(&(u64::MAX - 10)), None);
+        assert_eq!(table.get(&(u64::MAX - 9)), Some(&'z'));
+        assert_eq!(
+            table.get_key_value(&u64::MAX),
+            Some((&(u64::MAX - 9..=u64::MAX), &'z'))
+        );
+
+        assert_eq!(
+            IntervalTable::new(vec![(0..=5_u8, 'a'), (5..=255, 'b')]),
+            Err(IntervalError::Overlap(0..=5, 5..=255))
+        );
+        let inverted = RangeInclusive::new(7_u8, 3);
+        assert_eq!(
+            IntervalTable::new(vec![(inverted.clone(), 'a')]),
+            Err(IntervalError::Empty(inverted))
+        );
+        let table = IntervalTable::new(vec![(0..=0_u8, 'a'), (1..=255, 'b')]).unwrap();
+        assert_eq!(table.gaps().count(), 0);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let mut table = IntervalTable::new(vec![
+            (0..10, 'a'),
+            (10..20, 'a'),
+            (20..30, 'a'),
+            (30..40, 'b'),
+            (45..50, 'b'),
+            (50..60, 'c'),
+            (60..70, 'c'),
+        ])
+        .unwrap();
+        table.coalesce();
+        assert_eq!(
+            table.into_vec(),
+            [(0..30, 'a'), (30..40, 'b'), (45..50, 'b'), (50..70, 'c')]
+        );
+    }
+}
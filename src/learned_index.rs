@@ -0,0 +1,282 @@
+use crate::{InterpolationFactor, InterpolationSearch};
+use std::mem;
+
+/// A learned index over a sorted slice, in the style of the PGM-index.
+///
+/// The index partitions the distinct keys of the slice into segments and fits a linear model to
+/// each of them, which predicts the position of a key with a guaranteed maximum error of
+/// `epsilon`. A lookup picks the segment of the target, predicts its position and searches only
+/// the `[pos - epsilon, pos + epsilon]` window around the prediction.
+///
+/// Keys are mapped into the model space through [`InterpolationFactor`]: a key is represented by
+/// its interpolation factor between the first key of its segment and the last key of the slice.
+/// The number of segments, and thus the memory footprint, depends on how linear the keys are in
+/// that space. Uniformly distributed keys take only a handful of segments.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::LearnedIndex;
+///
+/// let arr = (0..10_000_u64).map(|n| n * n).collect::<Vec<_>>();
+/// let index = LearnedIndex::new(&arr, 8);
+///
+/// assert_eq!(index.search(&(5000 * 5000)), Ok(5000));
+/// assert_eq!(index.search(&10), Err(4));
+/// assert_eq!(index.epsilon(), 8);
+/// assert!(index.segment_count() < 1000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LearnedIndex<'a, T> {
+    slice: &'a [T],
+    segments: Vec<Segment>,
+    epsilon: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    // Position of the first occurrence of the key the segment starts with.
+    pos: usize,
+    // Positions per unit of interpolation factor.
+    slope: f64,
+}
+
+impl<'a, T> LearnedIndex<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    /// Builds the index over the sorted `slice`, with at most `epsilon` positions of error for the
+    /// predictions of its keys. The slice is scanned once.
+    pub fn new(slice: &'a [T], epsilon: usize) -> Self {
+        let mut segments = Vec::new();
+        let Some(last) = slice.last() else {
+            return Self {
+                slice,
+                segments,
+                epsilon,
+            };
+        };
+        let eps = epsilon as f64;
+        let mut start = 0;
+        // The range of slopes that keep every point of the current segment within the error.
+        let (mut min_slope, mut max_slope) = (f64::NEG_INFINITY, f64::INFINITY);
+        for pos in 1..slice.len() {
+            if slice[pos] == slice[pos - 1] {
+                continue;
+            }
+            let x = f64::from(slice[pos].interpolation_factor(&slice[start], last));
+            let offset = (pos - start) as f64;
+            let fits = if x > 0.0 {
+                let lo = min_slope.max((offset - eps) / x);
+                let hi = max_slope.min((offset + eps) / x);
+                if lo <= hi {
+                    (min_slope, max_slope) = (lo, hi);
+                }
+                lo <= hi
+            } else {
+                offset <= eps
+            };
+            if !fits {
+                segments.push(Segment::new(start, min_slope, max_slope));
+                start = pos;
+                (min_slope, max_slope) = (f64::NEG_INFINITY, f64::INFINITY);
+            }
+        }
+        segments.push(Segment::new(start, min_slope, max_slope));
+        segments.shrink_to_fit();
+        Self {
+            slice,
+            segments,
+            epsilon,
+        }
+    }
+
+    /// Returns the indexed slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+
+    /// Returns the maximum error of the predicted positions of the keys in the slice.
+    pub fn epsilon(&self) -> usize {
+        self.epsilon
+    }
+
+    /// Returns the number of linear segments the index consists of.
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the memory footprint of the index in bytes, excluding the indexed slice.
+    pub fn size_in_bytes(&self) -> usize {
+        mem::size_of::<Self>() + self.segments.capacity() * mem::size_of::<Segment>()
+    }
+
+    /// Returns the position of `target` predicted by the model. For keys of the slice, the
+    /// prediction is at most `epsilon` positions away from the first occurrence of the key.
+    pub fn predict(&self, target: &T) -> usize {
+        match self.segment(target) {
+            Some(seg) => self.predict_in(seg, target).round().max(0.0) as usize,
+            None => 0,
+        }
+    }
+
+    /// Searches the indexed slice for `target`.
+    ///
+    /// The result is the same as that of [`InterpolationSearch::interpolation_search`].
+    pub fn search(&self, target: &T) -> Result<usize, usize> {
+        let Some(last) = self.slice.last() else {
+            return Err(0);
+        };
+        if target > last {
+            return Err(self.slice.len());
+        }
+        let Some(seg) = self.segment(target) else {
+            return Err(0);
+        };
+        // The segment bounds the search: everything before it is less than `target`, everything
+        // from the start of the next segment on is greater.
+        let first = self.segments[seg].pos;
+        let last = self
+            .segments
+            .get(seg + 1)
+            .map_or(self.slice.len(), |next| next.pos);
+        // The window is `[pos - epsilon, pos + epsilon]`, and `hi` is its exclusive end.
+        let pos = self.predict_in(seg, target).round().max(0.0) as usize;
+        let lo = pos.saturating_sub(self.epsilon).clamp(first, last);
+        let hi = pos.saturating_add(self.epsilon + 1).clamp(lo, last);
+        let found = match self.slice[lo..hi].interpolation_search(target) {
+            Ok(idx) => return Ok(lo + idx),
+            Err(idx) => lo + idx,
+        };
+        // Keys that are not in the slice are not covered by the error guarantee, and neither are
+        // long runs of duplicates. Fall back to searching the whole segment if the insertion point
+        // is at an edge of the window and not confirmed by the neighbouring element.
+        let confirmed = match found {
+            idx if idx == lo && idx > first => self.slice[idx - 1] < *target,
+            idx if idx == hi && idx < last => self.slice[idx] >= *target,
+            _ => true,
+        };
+        if confirmed {
+            Err(found)
+        } else {
+            match self.slice[first..last].interpolation_search(target) {
+                Ok(idx) => Ok(first + idx),
+                Err(idx) => Err(first + idx),
+            }
+        }
+    }
+
+    // Returns the index of the last segment that starts with a key less than or equal to `target`.
+    fn segment(&self, target: &T) -> Option<usize> {
+        self.segments
+            .partition_point(|seg| self.slice[seg.pos] <= *target)
+            .checked_sub(1)
+    }
+
+    fn predict_in(&self, seg: usize, target: &T) -> f64 {
+        let Segment { pos, slope } = self.segments[seg];
+        let start = &self.slice[pos];
+        let last = &self.slice[self.slice.len() - 1];
+        if start == last || target == start {
+            pos as f64
+        } else {
+            pos as f64 + slope * f64::from(target.interpolation_factor(start, last))
+        }
+    }
+}
+
+impl Segment {
+    fn new(pos: usize, min_slope: f64, max_slope: f64) -> Self {
+        let slope = match (min_slope.is_finite(), max_slope.is_finite()) {
+            (true, true) => (min_slope + max_slope) / 2.0,
+            _ => 0.0,
+        };
+        Self { pos, slope }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_against_binary_search<T>(arr: &[T], epsilon: usize, targets: impl Iterator<Item = T>)
+    where
+        T: Ord + InterpolationFactor + std::fmt::Debug,
+    {
+        let index = LearnedIndex::new(arr, epsilon);
+        for (idx, key) in arr.iter().enumerate() {
+            let first = arr.partition_point(|x| x < key);
+            assert!(
+                index.predict(key).abs_diff(first) <= epsilon,
+                "prediction {} of {key:?} is too far from {first}",
+                index.predict(key)
+            );
+            assert!(index.search(key).is_ok_and(|found| arr[found] == arr[idx]));
+        }
+        for target in targets {
+            match index.search(&target) {
+                Ok(idx) => assert_eq!(arr[idx], target),
+                Err(idx) => assert_eq!(Err(idx), arr.binary_search(&target), "{target:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let arr: [u64; 0] = [];
+        let index = LearnedIndex::new(&arr, 4);
+        assert_eq!(index.segment_count(), 0);
+        assert_eq!(index.search(&1), Err(0));
+        assert_eq!(index.predict(&1), 0);
+    }
+
+    #[test]
+    fn test_single_element() {
+        let arr = [5_u64];
+        let index = LearnedIndex::new(&arr, 0);
+        assert_eq!(index.segment_count(), 1);
+        assert_eq!(index.search(&5), Ok(0));
+        assert_eq!(index.search(&4), Err(0));
+        assert_eq!(index.search(&6), Err(1));
+    }
+
+    #[test]
+    fn test_uniform() {
+        let arr = (0..100_000_u64).map(|n| n * 3).collect::<Vec<_>>();
+        let index = LearnedIndex::new(&arr, 2);
+        assert_eq!(index.segment_count(), 1);
+        assert_eq!(index.as_slice().len(), arr.len());
+        assert!(index.size_in_bytes() < 100);
+        assert_against_binary_search(&arr, 2, (0..300_010).step_by(7));
+    }
+
+    #[test]
+    fn test_skewed() {
+        let arr = (0..5000_u64).map(|n| n * n * n).collect::<Vec<_>>();
+        for epsilon in [0, 1, 4, 32, 1000] {
+            assert_against_binary_search(&arr, epsilon, (0..arr[4999] + 10).step_by(1_000_003));
+        }
+        assert!(
+            LearnedIndex::new(&arr, 32).segment_count()
+                < LearnedIndex::new(&arr, 1).segment_count()
+        );
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let arr = (0..2000_u32)
+            .flat_map(|n| std::iter::repeat_n(n * 10, (n % 50) as usize + 1))
+            .collect::<Vec<_>>();
+        assert_against_binary_search(&arr, 3, 0..20_010);
+    }
+
+    #[test]
+    fn test_strings() {
+        let mut arr = (0..3000_u32)
+            .map(|n| format!("key-{:x}", n * 7919))
+            .collect::<Vec<_>>();
+        arr.sort();
+        let targets = (0..3000_u32).map(|n| format!("key-{:x}", n * 31));
+        assert_against_binary_search(&arr, 4, targets);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! It improves upon the famous binary search by using linear interpolation to better estimate the targe's position within the array. Interpolation search reduces the asymptotic time complexity of the search to *O(log log N)*. However, in the worst case scenario (array elements grow exponentially) the complexity becomes linear (*O(N)*).
 //!
-//! To extend `slice` with the `interpolation_search` method this crate provides, import the `InterpolationSearch` trait. Now the `interpolation_search` method is available on arrays, slices, and `Vec`s:
+//! To extend `slice` with the `interpolation_search` method this crate provides, import the `InterpolationSearch` trait. Now the `interpolation_search` method is available on arrays, slices, and `Vec`s, as well as on `VecDeque`s, where it returns logical indices without making the buffer contiguous:
 //!
 //! ```
 //! use interpolation_search::InterpolationSearch;
@@ -64,12 +64,59 @@
 //!
 //! >Note: we couldn't just implement [`InterpolationFactor`] for the tuple `(u8, u8, u8)` as it's a foreign type. We're using the well-known [newtype idiom](https://doc.rust-lang.org/rust-by-example/generics/new_types.html).
 //!
+//! # Skewed distributions
+//!
+//! Interpolation works best when the keys are uniformly distributed. Keys that grow exponentially or quadratically can be straightened by wrapping them in [`LogScale`] or [`Sqrt`], or through an arbitrary monotonic mapping with [`MapFactor`]. The wrappers keep the ordering of the wrapped values and only change how they are interpolated. For arbitrary distributions, [`CdfModel`] samples the slice once and narrows every search down to a segment where the keys are close to uniform.
+//!
+//! ```
+//! use interpolation_search::{InterpolationSearch, LogScale};
+//!
+//! let arr = [1, 10, 100, 1_000, 10_000, 100_000].map(LogScale);
+//! assert_eq!(arr.interpolation_search(&LogScale(1_000)), Ok(3));
+//! ```
+//!
+//! # Optional integrations
+//!
+//! [`InterpolationFactor`] implementations for types of popular third-party crates are available behind opt-in cargo features, named after the crates: `chrono`, `time`, `uuid`, `ordered-float`, `rust_decimal` and `num-bigint`. None of them is enabled by default.
+//!
 //! # Consistency
 //!
 //! The [`InterpolationFactor`] property of a type must be consistent with its [`Ord`]. That is, for `a, b, c`, where `a <= b <= c`, `b.interpolation_factor(a, c)` must be in the `[0.0, 1.0]` range.
 
+mod case_insensitive;
+mod cdf_model;
+mod combinators;
+mod distance;
+mod gapped_index;
+mod integrations;
 mod interpolation_factor;
 mod interpolation_search;
+mod interpolation_search_tree;
+mod interval_table;
+mod learned_index;
+mod merge_join;
+mod natural_order;
+mod prefix_search;
+mod radix_table;
+pub mod set_ops;
+mod sorted_hash_set;
+pub mod sorted_map;
+mod sorted_vec;
 
+pub use case_insensitive::{AsciiCaseInsensitive, CaseInsensitive};
+pub use cdf_model::CdfModel;
+pub use combinators::{LogScale, MapFactor, Numeric, Sqrt};
+pub use distance::Distance;
+pub use gapped_index::GappedIndex;
 pub use interpolation_factor::InterpolationFactor;
-pub use interpolation_search::InterpolationSearch;
+pub use interpolation_search::{InterpolationRangeSlice, InterpolationSearch};
+pub use interpolation_search_tree::InterpolationSearchTree;
+pub use interval_table::{Interval, IntervalError, IntervalTable, Successor};
+pub use learned_index::LearnedIndex;
+pub use merge_join::MergeJoin;
+pub use natural_order::NaturalOrder;
+pub use prefix_search::PrefixSearch;
+pub use radix_table::{RadixKey, RadixTable};
+pub use sorted_hash_set::SortedHashSet;
+pub use sorted_map::SortedMap;
+pub use sorted_vec::SortedVec;
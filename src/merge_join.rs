@@ -0,0 +1,238 @@
+use crate::{
+    interpolation_search::{lower_bound_by_key, upper_bound_by_key},
+    InterpolationFactor,
+};
+use std::{iter::FusedIterator, ops::Range};
+
+/// A merge-join of two slices sorted by a key, which yields the index pairs of the matching
+/// elements.
+///
+/// Every element of `left` is matched with every element of `right` that has an equal key, so
+/// a group of `n` equal keys on the left and `m` on the right yields all `n * m` pairs, ordered by
+/// the left index, then the right index. Groups are yielded in key order.
+///
+/// Where one side runs ahead of the other, the other side leaps to the next candidate key with
+/// interpolation search instead of stepping element by element, so a join of a short and a long
+/// slice only visits the elements around the matches.
+///
+/// If a slice is not sorted by its key, the yielded pairs are unspecified and meaningless.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::MergeJoin;
+///
+/// struct Order { customer: u32, amount: u32 }
+/// struct Customer { id: u32, name: &'static str }
+///
+/// let orders = [
+///     Order { customer: 1, amount: 10 },
+///     Order { customer: 1, amount: 20 },
+///     Order { customer: 3, amount: 5 },
+///     Order { customer: 7, amount: 8 },
+/// ];
+/// let customers = [
+///     Customer { id: 1, name: "ann" },
+///     Customer { id: 2, name: "bob" },
+///     Customer { id: 7, name: "eve" },
+/// ];
+///
+/// let join = MergeJoin::new(&orders, &customers, |o| &o.customer, |c| &c.id);
+/// let rows = join
+///     .map(|(o, c)| (customers[c].name, orders[o].amount))
+///     .collect::<Vec<_>>();
+/// assert_eq!(rows, [("ann", 10), ("ann", 20), ("eve", 8)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MergeJoin<'a, L, R, F, G> {
+    left: &'a [L],
+    right: &'a [R],
+    left_key: F,
+    right_key: G,
+    // The next unmatched positions of both sides.
+    left_idx: usize,
+    right_idx: usize,
+    // The current group of equal keys and the next pair of it to yield.
+    group: Option<(Range<usize>, Range<usize>)>,
+    pair: (usize, usize),
+}
+
+impl<'a, L, R, K, F, G> MergeJoin<'a, L, R, F, G>
+where
+    K: Ord + InterpolationFactor,
+    F: FnMut(&L) -> &K,
+    G: FnMut(&R) -> &K,
+{
+    /// Creates a merge-join of `left` and `right`, sorted by the keys that `left_key` and
+    /// `right_key` extract.
+    pub fn new(left: &'a [L], right: &'a [R], left_key: F, right_key: G) -> Self {
+        Self {
+            left,
+            right,
+            left_key,
+            right_key,
+            left_idx: 0,
+            right_idx: 0,
+            group: None,
+            pair: (0, 0),
+        }
+    }
+
+    // Advances both sides to the next key they have in common, and returns its group.
+    fn next_group(&mut self) -> Option<(Range<usize>, Range<usize>)> {
+        loop {
+            let left_key = (self.left_key)(self.left.get(self.left_idx)?);
+            let right_key = (self.right_key)(self.right.get(self.right_idx)?);
+            if left_key < right_key {
+                let rest = &self.left[self.left_idx..];
+                self.left_idx += leap(rest, right_key, &mut self.left_key);
+            } else if right_key < left_key {
+                let rest = &self.right[self.right_idx..];
+                self.right_idx += leap(rest, left_key, &mut self.right_key);
+            } else {
+                let left_end = self.left_idx
+                    + upper_bound_by_key(&self.left[self.left_idx..], left_key, &mut self.left_key);
+                let right_end = self.right_idx
+                    + upper_bound_by_key(
+                        &self.right[self.right_idx..],
+                        right_key,
+                        &mut self.right_key,
+                    );
+                let group = (self.left_idx..left_end, self.right_idx..right_end);
+                (self.left_idx, self.right_idx) = (left_end, right_end);
+                return Some(group);
+            }
+        }
+    }
+}
+
+// Returns the number of leading elements of `rest` with a key less than `target`. The first
+// element is known to be less, and most of the time the second one isn't, so that's checked
+// before searching.
+fn leap<T, K, F>(rest: &[T], target: &K, mut key: F) -> usize
+where
+    K: Ord + InterpolationFactor,
+    F: FnMut(&T) -> &K,
+{
+    match rest.get(1) {
+        Some(second) if key(second) < target => 2 + lower_bound_by_key(&rest[2..], target, key),
+        _ => 1,
+    }
+}
+
+impl<L, R, K, F, G> Iterator for MergeJoin<'_, L, R, F, G>
+where
+    K: Ord + InterpolationFactor,
+    F: FnMut(&L) -> &K,
+    G: FnMut(&R) -> &K,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        let (left, right) = match &self.group {
+            Some(group) => group.clone(),
+            None => {
+                let group = self.next_group()?;
+                self.pair = (group.0.start, group.1.start);
+                self.group = Some(group.clone());
+                group
+            }
+        };
+        let pair = self.pair;
+        self.pair.1 += 1;
+        if self.pair.1 == right.end {
+            self.pair = (self.pair.0 + 1, right.start);
+            if self.pair.0 == left.end {
+                self.group = None;
+            }
+        }
+        Some(pair)
+    }
+}
+
+impl<L, R, K, F, G> FusedIterator for MergeJoin<'_, L, R, F, G>
+where
+    K: Ord + InterpolationFactor,
+    F: FnMut(&L) -> &K,
+    G: FnMut(&R) -> &K,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested_loop_join(left: &[u32], right: &[u32]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, l) in left.iter().enumerate() {
+            for (j, r) in right.iter().enumerate() {
+                if l == r {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty: [u32; 0] = [];
+        assert_eq!(MergeJoin::new(&empty, &[1], |x| x, |x| x).next(), None);
+        assert_eq!(MergeJoin::new(&[1], &empty, |x| x, |x| x).next(), None);
+        assert_eq!(MergeJoin::new(&[1, 3], &[2, 4], |x| x, |x| x).next(), None);
+    }
+
+    #[test]
+    fn test_many_to_many() {
+        let left = [1, 2, 2, 2, 4, 5, 5];
+        let right = [0, 2, 2, 3, 5, 5, 5, 6];
+        let join = MergeJoin::new(&left, &right, |x| x, |x| x);
+        assert_eq!(join.collect::<Vec<_>>(), nested_loop_join(&left, &right));
+    }
+
+    #[test]
+    fn test_against_nested_loop() {
+        let dense = (0..2000).map(|n| n / 3).collect::<Vec<u32>>();
+        let sparse = (0..50).map(|n| n * n / 2).collect::<Vec<u32>>();
+        let far = (0..100).map(|n| n * 10_000).collect::<Vec<u32>>();
+        for (left, right) in [
+            (&dense, &sparse),
+            (&sparse, &dense),
+            (&dense, &far),
+            (&far, &dense),
+            (&dense, &dense),
+        ] {
+            let join = MergeJoin::new(left, right, |x| x, |x| x);
+            assert_eq!(join.collect::<Vec<_>>(), nested_loop_join(left, right));
+        }
+    }
+
+    #[test]
+    fn test_leaps() {
+        let long = (0..100_000).collect::<Vec<u32>>();
+        let short = [5, 50_000, 99_999];
+        let mut probes = 0;
+        let join = MergeJoin::new(
+            &long,
+            &short,
+            |x| {
+                probes += 1;
+                x
+            },
+            |x| x,
+        );
+        assert_eq!(join.collect::<Vec<_>>(), [(5, 0), (50_000, 1), (99_999, 2)]);
+        assert!(probes < 100);
+    }
+
+    #[test]
+    fn test_different_types() {
+        let names = [(1, "a"), (2, "b"), (4, "d")];
+        let scores = [(10, 1_u64), (20, 1), (30, 3), (40, 4)];
+        let join = MergeJoin::new(&names, &scores, |(id, _)| id, |(_, id)| id);
+        let rows = join
+            .map(|(n, s)| (names[n].1, scores[s].0))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [("a", 10), ("a", 20), ("d", 40)]);
+    }
+}
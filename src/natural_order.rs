@@ -0,0 +1,230 @@
+use crate::{
+    combinators::{f64_factor, from_ref},
+    interpolation_factor::first_difference,
+    InterpolationFactor,
+};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Orders and interpolates the wrapped string in natural order, where runs of ASCII digits compare
+/// as numbers: "file9" comes before "file10", and "v1.2.10" after "v1.2.9".
+///
+/// Everything else compares by character, and a number compares to a character as its digits do.
+/// Numbers that only differ in leading zeros, as in "a01" and "a1", are ordered by the plain string
+/// comparison, so only equal strings are equal.
+///
+/// Within a digit run the interpolation factor is the one of the numbers, and elsewhere the one of
+/// the first characters that differ, as for plain strings.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::{InterpolationSearch, NaturalOrder};
+///
+/// let mut files = vec!["file10.txt", "file9.txt", "file1.txt", "file100.txt", "file09b.txt"];
+/// files.sort_by(|a, b| NaturalOrder(a).cmp(&NaturalOrder(b)));
+/// assert_eq!(files, ["file1.txt", "file9.txt", "file09b.txt", "file10.txt", "file100.txt"]);
+///
+/// let target = NaturalOrder::from_ref("file10.txt");
+/// let idx = files.interpolation_search_by_key(target, |file| NaturalOrder::from_ref(*file));
+/// assert_eq!(idx, Ok(3));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct NaturalOrder<S: ?Sized>(pub S);
+
+from_ref!(NaturalOrder<S: ?Sized>);
+
+impl<S: AsRef<str> + ?Sized> PartialEq for NaturalOrder<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Eq for NaturalOrder<S> {}
+
+impl<S: AsRef<str> + ?Sized> PartialOrd for NaturalOrder<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Ord for NaturalOrder<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.0.as_ref(), other.0.as_ref());
+        tokens(a).cmp(tokens(b)).then_with(|| a.cmp(b))
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Hash for NaturalOrder<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ref().hash(state);
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> InterpolationFactor for NaturalOrder<S> {
+    fn interpolation_factor(&self, a: &Self, b: &Self) -> f32 {
+        let (mid, a, b) = (self.0.as_ref(), a.0.as_ref(), b.0.as_ref());
+        match first_difference(tokens(mid), tokens(a), tokens(b)) {
+            Some((Token::Number(mid), Token::Number(a), Token::Number(b))) => {
+                f64_factor(to_f64(mid), to_f64(a), to_f64(b))
+            }
+            Some((mid, a, b)) => mid
+                .to_char()
+                .interpolation_factor(&a.to_char(), &b.to_char()),
+            None => 0.5,
+        }
+    }
+}
+
+// A character, or a run of ASCII digits without its leading zeros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Char(char),
+    Number(&'a str),
+}
+
+impl Token<'_> {
+    // All numbers are on the same side of any other character, so any digit stands in for them.
+    fn to_char(self) -> char {
+        match self {
+            Token::Char(c) => c,
+            Token::Number(_) => '0',
+        }
+    }
+}
+
+impl PartialOrd for Token<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Token<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Token::Number(a), Token::Number(b)) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+            _ => self.to_char().cmp(&other.to_char()),
+        }
+    }
+}
+
+fn tokens(s: &str) -> impl Iterator<Item = Token<'_>> + Clone {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let c = rest.chars().next()?;
+        if !c.is_ascii_digit() {
+            rest = &rest[c.len_utf8()..];
+            return Some(Token::Char(c));
+        }
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (digits, tail) = rest.split_at(len);
+        rest = tail;
+        Some(Token::Number(digits.trim_start_matches('0')))
+    })
+}
+
+// Numbers too long for `f64` become infinite. If only the upper bound is infinite, `f64_factor`
+// clamps the factor of a finite number to 0, and if the number is infinite as well, the factor is
+// NaN, which it maps to the middle of the range.
+fn to_f64(digits: &str) -> f64 {
+    digits
+        .bytes()
+        .fold(0.0, |acc, d| acc * 10.0 + f64::from(d - b'0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterpolationSearch;
+
+    fn natural(s: &str) -> &NaturalOrder<str> {
+        NaturalOrder::from_ref(s)
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(natural("file9") < natural("file10"));
+        assert!(natural("file10") < natural("file10a"));
+        assert!(natural("v1.2.9") < natural("v1.2.10"));
+        assert!(natural("v1.10") < natural("v2"));
+        assert!(natural("a01") < natural("a1"));
+        assert!(natural("a1") < natural("a02"));
+        assert!(natural("a") < natural("a0"));
+        assert!(natural("1") < natural("a"));
+        assert!(natural("!") < natural("0"));
+        assert!(natural("99999999999999999999999") < natural("100000000000000000000000"));
+        assert_eq!(natural("x007"), natural("x007"));
+        assert_ne!(natural("x007"), natural("x7"));
+    }
+
+    #[test]
+    fn test_total_order() {
+        let samples = [
+            "", "0", "00", "1", "01", "9", "10", "a", "a0", "a00", "a1", "a01", "a1b", "a10", "b",
+            "é1", "!", "1!", "1a", "10a",
+        ];
+        for a in samples {
+            for b in samples {
+                let ab = natural(a).cmp(natural(b));
+                assert_eq!(ab, natural(b).cmp(natural(a)).reverse(), "{a:?} {b:?}");
+                assert_eq!(ab == Ordering::Equal, a == b, "{a:?} {b:?}");
+                for c in samples {
+                    if ab.is_le() && natural(b) <= natural(c) {
+                        assert!(natural(a) <= natural(c), "{a:?} {b:?} {c:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolation_factor() {
+        let (a, b) = (natural("img0"), natural("img1000"));
+        assert_eq!(natural("img250").interpolation_factor(a, b), 0.25);
+        assert_eq!(natural("img0").interpolation_factor(a, b), 0.0);
+        assert_eq!(natural("img1000").interpolation_factor(a, b), 1.0);
+        assert_eq!(
+            natural("v1.m").interpolation_factor(natural("v1.a"), natural("v1.z")),
+            'm'.interpolation_factor(&'a', &'z')
+        );
+        assert_eq!(
+            natural("same").interpolation_factor(natural("same"), natural("same")),
+            0.5
+        );
+        let (huge, huger) = (format!("n{:0<400}", 1), format!("n{:0<401}", 1));
+        let (a, b) = (natural("n1"), natural(&huger));
+        assert_eq!(to_f64(&huger[1..]), f64::INFINITY);
+        assert_eq!(natural("n5").interpolation_factor(a, b), 0.0);
+        assert_eq!(natural(&huge).interpolation_factor(a, b), 0.5);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut files = (0..5000_u32)
+            .map(|n| format!("frame{}.png", n * 3))
+            .collect::<Vec<_>>();
+        files.sort_by(|a, b| NaturalOrder(a).cmp(&NaturalOrder(b)));
+        assert_eq!(files[..3], ["frame0.png", "frame3.png", "frame6.png"]);
+        let mut probes = 0;
+        for n in 0..15_000_u32 {
+            let target = format!("frame{n}.png");
+            let expected = if n % 3 == 0 {
+                Ok(n as usize / 3)
+            } else {
+                Err(n as usize / 3 + 1)
+            };
+            let found = files.interpolation_search_by_key(natural(&target), |file| {
+                probes += 1;
+                natural(file)
+            });
+            assert_eq!(found, expected, "{target}");
+        }
+        // Evenly spaced numbers are found in a handful of probes each.
+        assert!(probes < 15_000 * 10);
+    }
+}
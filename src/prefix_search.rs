@@ -0,0 +1,172 @@
+use crate::interpolation_search::lower_bound_by_key;
+use std::ops::Range;
+
+pub trait PrefixSearch<T> {
+    /// Returns the range of indices of the strings that start with `prefix`. If the slice is not sorted, the returned range is unspecified and meaningless.
+    ///
+    /// The first string that is not less than `prefix` is located with interpolation search. The end of the range is the first string that is not less than the successor of `prefix`, the least string greater than every string starting with `prefix`, and it's only searched for after the start. If no string starts with `prefix`, the returned range is empty and starts where such a string could be inserted.
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::PrefixSearch;
+    ///
+    /// let words = ["apple", "apply", "apricot", "banana", "band", "bandana", "cherry"];
+    ///
+    /// assert_eq!(words.interpolation_prefix_range("ap"), 0..3);
+    /// assert_eq!(words.interpolation_prefix_range("band"), 4..6);
+    /// assert_eq!(words.interpolation_prefix_range("bz"), 6..6);
+    /// assert_eq!(words.interpolation_prefix_range(""), 0..7);
+    /// ```
+    fn interpolation_prefix_range(&self, prefix: &str) -> Range<usize>
+    where
+        T: AsRef<str>;
+
+    /// Returns the range of indices of the elements with keys that start with `prefix`, using a key extraction function. If the slice is not sorted by keys, the returned range is unspecified and meaningless.
+    ///
+    /// The range is located as in [`interpolation_prefix_range`](PrefixSearch::interpolation_prefix_range).
+    ///
+    /// **Examples**
+    ///
+    /// ```
+    /// use interpolation_search::PrefixSearch;
+    ///
+    /// let commands = [("commit", 3), ("config", 1), ("diff", 7), ("status", 2)];
+    ///
+    /// assert_eq!(commands.interpolation_prefix_range_by_key("co", |(name, _)| name), 0..2);
+    /// ```
+    fn interpolation_prefix_range_by_key<F>(&self, prefix: &str, f: F) -> Range<usize>
+    where
+        F: FnMut(&T) -> &str;
+}
+
+impl<T> PrefixSearch<T> for [T] {
+    fn interpolation_prefix_range(&self, prefix: &str) -> Range<usize>
+    where
+        T: AsRef<str>,
+    {
+        self.interpolation_prefix_range_by_key(prefix, |s| s.as_ref())
+    }
+
+    fn interpolation_prefix_range_by_key<F>(&self, prefix: &str, mut key: F) -> Range<usize>
+    where
+        F: FnMut(&T) -> &str,
+    {
+        let start = lower_bound_by_key(self, prefix, &mut key);
+        let rest = &self[start..];
+        let len = match successor(prefix) {
+            Some(successor) => lower_bound_by_key(rest, successor.as_str(), &mut key),
+            None => rest.len(),
+        };
+        start..start + len
+    }
+}
+
+// Returns the least string that is greater than every string starting with `prefix`, or `None` if
+// there's no such string. Strings are ordered by their code points, so that's `prefix` with its
+// trailing `char::MAX`s dropped and the last remaining character incremented.
+fn successor(prefix: &str) -> Option<String> {
+    let trimmed = prefix.trim_end_matches(char::MAX);
+    let last = trimmed.chars().next_back()?;
+    // There's always a next character: `last` is not `char::MAX`.
+    let next = (u32::from(last) + 1..)
+        .find_map(char::from_u32)
+        .expect("a character after a non-maximal one");
+    let mut successor = trimmed[..trimmed.len() - last.len_utf8()].to_owned();
+    successor.push(next);
+    Some(successor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_against_filter(words: &[&str], prefix: &str) {
+        let range = words.interpolation_prefix_range(prefix);
+        let expected = words
+            .iter()
+            .filter(|word| word.starts_with(prefix))
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(words[range.clone()], expected, "{prefix:?}");
+        assert_eq!(range.start, words.partition_point(|word| *word < prefix));
+    }
+
+    #[test]
+    fn test_successor() {
+        assert_eq!(successor("abc").as_deref(), Some("abd"));
+        assert_eq!(successor("az").as_deref(), Some("a{"));
+        assert_eq!(successor("a\u{10FFFF}").as_deref(), Some("b"));
+        assert_eq!(successor("\u{D7FF}").as_deref(), Some("\u{E000}"));
+        assert_eq!(successor("\u{10FFFF}\u{10FFFF}"), None);
+        assert_eq!(successor(""), None);
+    }
+
+    #[test]
+    fn test_against_filter() {
+        let mut words = vec![
+            "",
+            "a",
+            "ab",
+            "abc",
+            "abd",
+            "ac",
+            "b",
+            "ba",
+            "bab",
+            "z",
+            "zz",
+            "zzz",
+            "é",
+            "éa",
+            "日本",
+            "日本語",
+            "\u{10FFFF}",
+            "\u{10FFFF}a",
+        ];
+        words.sort();
+        for prefix in [
+            "",
+            "a",
+            "ab",
+            "abc",
+            "abz",
+            "b",
+            "bb",
+            "z",
+            "zz",
+            "é",
+            "日",
+            "日本語",
+            "\u{10FFFF}",
+            "\u{10FFFF}a",
+            "\u{10FFFF}\u{10FFFF}",
+        ] {
+            assert_against_filter(&words, prefix);
+        }
+    }
+
+    #[test]
+    fn test_autocomplete() {
+        let mut words = (0..5000_u32)
+            .map(|n| format!("{:x}", n.wrapping_mul(2_654_435_761)))
+            .collect::<Vec<_>>();
+        words.sort();
+        for prefix in ["", "0", "1", "a", "ab", "abc", "fff", "ffffffff", "g"] {
+            let range = words.interpolation_prefix_range(prefix);
+            assert!(words[range.clone()].iter().all(|w| w.starts_with(prefix)));
+            assert_eq!(
+                range.len(),
+                words.iter().filter(|w| w.starts_with(prefix)).count()
+            );
+        }
+        let entries = words
+            .iter()
+            .map(|w| (w.as_str(), w.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries.interpolation_prefix_range_by_key("ab", |(w, _)| w),
+            words.interpolation_prefix_range("ab")
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{InterpolationFactor, InterpolationSearch};
+use std::{mem, ops::Range};
+
+/// Integer-like keys that can be indexed by a [`RadixTable`].
+///
+/// The key is mapped onto an unsigned integer, such that the order of the keys is preserved.
+pub trait RadixKey: Ord + InterpolationFactor {
+    /// Returns the order-preserving unsigned representation of the key.
+    fn to_radix(&self) -> u128;
+}
+
+macro_rules! unsigned_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                fn to_radix(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! signed_radix_key {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                fn to_radix(&self) -> u128 {
+                    // Flipping the sign bit moves the negative values before the positive ones.
+                    (*self as i128 as u128) ^ (1 << 127)
+                }
+            }
+        )*
+    };
+}
+
+unsigned_radix_key!(u8, u16, u32, u64, u128, usize);
+signed_radix_key!(i8, i16, i32, i64, i128, isize);
+
+impl RadixKey for char {
+    fn to_radix(&self) -> u128 {
+        u32::from(*self).into()
+    }
+}
+
+/// A radix prefix table over a sorted slice of integer keys, which brackets the search for a key
+/// before interpolation even starts.
+///
+/// The table maps the top `radix_bits` bits of a key, relative to the smallest key of the slice,
+/// to the range of indices holding keys with that prefix. A lookup jumps straight to that range
+/// and runs [`InterpolationSearch::interpolation_search`] on it. For uniformly distributed keys,
+/// such as hashes, the ranges hold a few elements each, making the search essentially *O(1)*.
+///
+/// The table takes `2^radix_bits + 1` indices of memory at most.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::RadixTable;
+///
+/// let arr = (0..1000_u64).map(|n| n * 7_777_777).collect::<Vec<_>>();
+/// let table = RadixTable::new(&arr, 8);
+///
+/// assert_eq!(table.search(&(500 * 7_777_777)), Ok(500));
+/// assert_eq!(table.search(&1), Err(1));
+/// assert!(table.bracket(&(500 * 7_777_777)).len() <= 8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadixTable<'a, T> {
+    slice: &'a [T],
+    // `offsets[p]` is the index of the first key with a prefix of at least `p`.
+    offsets: Vec<usize>,
+    min: u128,
+    shift: u32,
+    radix_bits: u32,
+}
+
+impl<'a, T: RadixKey> RadixTable<'a, T> {
+    /// Builds the table over the sorted `slice`, indexing keys by their top `radix_bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix_bits` is greater than 32.
+    pub fn new(slice: &'a [T], radix_bits: u32) -> Self {
+        assert!(radix_bits <= 32, "at most 32 radix bits are supported");
+        let (min, span) = match (slice.first(), slice.last()) {
+            (Some(first), Some(last)) => (
+                first.to_radix(),
+                last.to_radix().saturating_sub(first.to_radix()),
+            ),
+            _ => (0, 0),
+        };
+        let shift = (u128::BITS - span.leading_zeros()).saturating_sub(radix_bits);
+        let mut offsets = vec![0; (span >> shift) as usize + 2];
+        // Keys out of order are clamped into the table rather than overflowing it, so that an
+        // unsorted slice gives meaningless results instead of a panic.
+        let last = offsets.len() - 1;
+        for key in slice {
+            let prefix = key.to_radix().saturating_sub(min) >> shift;
+            offsets[usize::try_from(prefix).map_or(last, |p| (p + 1).min(last))] += 1;
+        }
+        for p in 1..offsets.len() {
+            offsets[p] += offsets[p - 1];
+        }
+        Self {
+            slice,
+            offsets,
+            min,
+            shift,
+            radix_bits,
+        }
+    }
+
+    /// Returns the indexed slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+
+    /// Returns the number of key bits the table is indexed by.
+    pub fn radix_bits(&self) -> u32 {
+        self.radix_bits
+    }
+
+    /// Returns the memory footprint of the table in bytes, excluding the indexed slice.
+    pub fn size_in_bytes(&self) -> usize {
+        mem::size_of::<Self>() + self.offsets.capacity() * mem::size_of::<usize>()
+    }
+
+    /// Returns the range of indices that holds the keys with the same prefix as `target`. If
+    /// `target` is in the slice, it's in this range. Otherwise the range is empty or its insertion
+    /// point is within the range or at its ends.
+    pub fn bracket(&self, target: &T) -> Range<usize> {
+        let Some(offset) = target.to_radix().checked_sub(self.min) else {
+            return 0..0;
+        };
+        match usize::try_from(offset >> self.shift) {
+            Ok(p) if p + 1 < self.offsets.len() => self.offsets[p]..self.offsets[p + 1],
+            _ => self.slice.len()..self.slice.len(),
+        }
+    }
+
+    /// Searches the indexed slice for `target`.
+    ///
+    /// The result is the same as that of [`InterpolationSearch::interpolation_search`].
+    pub fn search(&self, target: &T) -> Result<usize, usize> {
+        let Range { start, end } = self.bracket(target);
+        match self.slice[start..end].interpolation_search(target) {
+            Ok(idx) => Ok(start + idx),
+            Err(idx) => Err(start + idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radix_key() {
+        assert!((-1_i32).to_radix() < 0_i32.to_radix());
+        assert!(i64::MIN.to_radix() < i64::MAX.to_radix());
+        assert!(i8::MIN.to_radix() < (-100_i8).to_radix());
+        assert_eq!(u128::MAX.to_radix(), u128::MAX);
+        assert!('a'.to_radix() < 'b'.to_radix());
+    }
+
+    #[test]
+    fn test_empty() {
+        let arr: [u64; 0] = [];
+        let table = RadixTable::new(&arr, 8);
+        assert_eq!(table.search(&0), Err(0));
+        assert_eq!(table.search(&100), Err(0));
+        assert_eq!(table.bracket(&100), 0..0);
+    }
+
+    #[test]
+    fn test_single_element() {
+        let arr = [5_u32];
+        let table = RadixTable::new(&arr, 8);
+        assert_eq!(table.search(&5), Ok(0));
+        assert_eq!(table.search(&4), Err(0));
+        assert_eq!(table.search(&6), Err(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_bits() {
+        RadixTable::new(&[1_u64], 33);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        let arr = [50_u32, 3, 900, 7, 0, u32::MAX, 12];
+        for bits in [0, 4, 32] {
+            let table = RadixTable::new(&arr, bits);
+            for target in [0, 7, 50, 500, u32::MAX] {
+                let range = table.bracket(&target);
+                assert!(range.start <= range.end && range.end <= arr.len());
+                let _ = table.search(&target);
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_binary_search() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut arr = (0..10_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            })
+            .collect::<Vec<_>>();
+        arr.sort();
+        for bits in [0, 1, 8, 12, 20] {
+            let table = RadixTable::new(&arr, bits);
+            assert!(table.size_in_bytes() <= mem::size_of_val(&table) + (8 << bits) + 16);
+            for (idx, key) in arr.iter().enumerate() {
+                assert_eq!(table.search(key), Ok(idx));
+                assert!(table.bracket(key).contains(&idx));
+            }
+            for key in arr
+                .iter()
+                .map(|key| key.wrapping_add(1))
+                .chain([0, u64::MAX])
+            {
+                assert_eq!(table.search(&key), arr.binary_search(&key));
+            }
+        }
+        // Uniform keys split evenly among the prefixes.
+        let table = RadixTable::new(&arr, 12);
+        assert!(arr.iter().all(|key| table.bracket(key).len() < 16));
+    }
+
+    #[test]
+    fn test_signed_keys_with_duplicates() {
+        let arr = (-500..500_i32)
+            .flat_map(|n| [n * 1000, n * 1000])
+            .collect::<Vec<_>>();
+        let table = RadixTable::new(&arr, 6);
+        for target in (-600_000..600_000).step_by(250) {
+            match table.search(&target) {
+                Ok(idx) => assert_eq!(arr[idx], target),
+                Err(idx) => assert_eq!(Err(idx), arr.binary_search(&target)),
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Set operations on sorted slices, which skip ahead with interpolation search.
+//!
+//! A plain merge of two sorted slices visits every element of both. The intersection and the
+//! difference instead search for the next element of one slice in the other, so they only visit
+//! the elements around the matches: intersecting a short posting list with a long one costs about
+//! as many interpolation searches as the short list has elements. The union has to yield every
+//! element anyway, so it is a plain merge.
+//!
+//! The slices must be sorted, otherwise the yielded elements are unspecified and meaningless. The
+//! slices are treated as sets: every distinct element is yielded at most once, in sorted order.
+//!
+//! # Examples
+//!
+//! ```
+//! use interpolation_search::set_ops;
+//!
+//! let a = [1, 3, 5, 7, 9];
+//! let b = [3, 4, 5, 6];
+//!
+//! assert!(set_ops::intersection(&a, &b).eq(&[3, 5]));
+//! assert!(set_ops::union(&a, &b).eq(&[1, 3, 4, 5, 6, 7, 9]));
+//! assert!(set_ops::difference(&a, &b).eq(&[1, 7, 9]));
+//! assert!(set_ops::intersection_all([&a[..], &b, &[5, 9]]).eq(&[5]));
+//! ```
+
+use crate::{
+    interpolation_search::{lower_bound_by_key, upper_bound_by_key},
+    InterpolationFactor,
+};
+use std::iter::FusedIterator;
+
+/// Returns an iterator over the elements that are in both `a` and `b`.
+pub fn intersection<'a, T>(a: &'a [T], b: &'a [T]) -> Intersection<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    Intersection { a, b }
+}
+
+/// Returns an iterator over the elements that are in `a` or `b`.
+pub fn union<'a, T>(a: &'a [T], b: &'a [T]) -> Union<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    Union { a, b }
+}
+
+/// Returns an iterator over the elements that are in `a` but not in `b`.
+pub fn difference<'a, T>(a: &'a [T], b: &'a [T]) -> Difference<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    Difference { a, b }
+}
+
+/// Returns an iterator over the elements that are in all of `slices`. The intersection of no
+/// slices is empty.
+pub fn intersection_all<'a, T, I>(slices: I) -> IntersectionAll<'a, T>
+where
+    T: Ord + InterpolationFactor,
+    I: IntoIterator<Item = &'a [T]>,
+{
+    let mut slices = slices.into_iter().collect::<Vec<_>>();
+    // The shortest slice proposes the first candidates, which skips the most.
+    slices.sort_by_key(|slice| slice.len());
+    IntersectionAll { slices }
+}
+
+/// Returns an iterator over the elements that are in any of `slices`.
+pub fn union_all<'a, T, I>(slices: I) -> UnionAll<'a, T>
+where
+    T: Ord + InterpolationFactor,
+    I: IntoIterator<Item = &'a [T]>,
+{
+    UnionAll {
+        slices: slices.into_iter().collect(),
+    }
+}
+
+/// An iterator over the intersection of two sorted slices.
+///
+/// This is created by [`intersection`].
+#[derive(Debug, Clone)]
+pub struct Intersection<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+/// An iterator over the union of two sorted slices.
+///
+/// This is created by [`union`].
+#[derive(Debug, Clone)]
+pub struct Union<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+/// An iterator over the difference of two sorted slices.
+///
+/// This is created by [`difference`].
+#[derive(Debug, Clone)]
+pub struct Difference<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+/// An iterator over the intersection of any number of sorted slices.
+///
+/// This is created by [`intersection_all`].
+#[derive(Debug, Clone)]
+pub struct IntersectionAll<'a, T> {
+    slices: Vec<&'a [T]>,
+}
+
+/// An iterator over the union of any number of sorted slices.
+///
+/// This is created by [`union_all`].
+#[derive(Debug, Clone)]
+pub struct UnionAll<'a, T> {
+    slices: Vec<&'a [T]>,
+}
+
+impl<'a, T> Iterator for Intersection<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            // Search for the next element of the shorter slice in the longer one.
+            let (short, long) = if self.a.len() <= self.b.len() {
+                (&mut self.a, &mut self.b)
+            } else {
+                (&mut self.b, &mut self.a)
+            };
+            let target = short.first()?;
+            *short = skip_run(short, target);
+            *long = &long[lower_bound_by_key(long, target, |x| x)..];
+            if long.first() == Some(target) {
+                *long = skip_run(long, target);
+                return Some(target);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len().min(self.b.len())))
+    }
+}
+
+impl<'a, T> Iterator for Union<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let next = match (self.a.first(), self.b.first()) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) => a,
+            (None, Some(b)) => b,
+            (None, None) => return None,
+        };
+        self.a = skip_run(self.a, next);
+        self.b = skip_run(self.b, next);
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.len(), self.b.len());
+        (a.min(1).max(b.min(1)), Some(a + b))
+    }
+}
+
+impl<'a, T> Iterator for Difference<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let target = self.a.first()?;
+            self.a = skip_run(self.a, target);
+            self.b = &self.b[lower_bound_by_key(self.b, target, |x| x)..];
+            if self.b.first() != Some(target) {
+                return Some(target);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len()))
+    }
+}
+
+impl<'a, T> Iterator for IntersectionAll<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let k = self.slices.len();
+        let mut candidate = self.slices.first()?.first()?;
+        // Leapfrog through the slices until `k` of them in a row agree on the candidate.
+        let (mut i, mut agreed) = (0, 0);
+        while agreed < k {
+            let slice = &mut self.slices[i];
+            *slice = &slice[lower_bound_by_key(slice, candidate, |x| x)..];
+            let head = slice.first()?;
+            if head == candidate {
+                agreed += 1;
+            } else {
+                candidate = head;
+                agreed = 1;
+            }
+            i = (i + 1) % k;
+        }
+        for slice in &mut self.slices {
+            *slice = skip_run(slice, candidate);
+        }
+        Some(candidate)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.slices.iter().map(|slice| slice.len()).min())
+    }
+}
+
+impl<'a, T> Iterator for UnionAll<'a, T>
+where
+    T: Ord + InterpolationFactor,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let next = self.slices.iter().filter_map(|slice| slice.first()).min()?;
+        for slice in &mut self.slices {
+            *slice = skip_run(slice, next);
+        }
+        Some(next)
+    }
+}
+
+impl<T: Ord + InterpolationFactor> FusedIterator for Intersection<'_, T> {}
+impl<T: Ord + InterpolationFactor> FusedIterator for Union<'_, T> {}
+impl<T: Ord + InterpolationFactor> FusedIterator for Difference<'_, T> {}
+impl<T: Ord + InterpolationFactor> FusedIterator for IntersectionAll<'_, T> {}
+impl<T: Ord + InterpolationFactor> FusedIterator for UnionAll<'_, T> {}
+
+// Returns the rest of `slice` after the leading elements equal to `target`.
+fn skip_run<'a, T>(slice: &'a [T], target: &T) -> &'a [T]
+where
+    T: Ord + InterpolationFactor,
+{
+    match slice {
+        [first, ..] if first != target => slice,
+        // Most runs are a single element long, which doesn't need a search.
+        [_, second, ..] if second != target => &slice[1..],
+        _ => &slice[upper_bound_by_key(slice, target, |x| x)..],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn sample(len: usize, step: u64, seed: u64) -> Vec<u64> {
+        let mut state = seed;
+        let mut vec = (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % (len as u64 * step)
+            })
+            .collect::<Vec<_>>();
+        vec.sort();
+        vec
+    }
+
+    #[test]
+    fn test_against_btree_set() {
+        for (len_a, len_b) in [(0, 0), (0, 10), (10, 0), (50, 50), (20, 5000), (5000, 20)] {
+            let a = sample(len_a, 3, 0x2545_f491_4f6c_dd1d);
+            let b = sample(len_b, 2, 0x9e37_79b9_7f4a_7c15);
+            let (set_a, set_b) = (a.iter().collect::<BTreeSet<_>>(), b.iter().collect());
+            assert!(intersection(&a, &b).eq(set_a.intersection(&set_b).copied()));
+            assert!(union(&a, &b).eq(set_a.union(&set_b).copied()));
+            assert!(difference(&a, &b).eq(set_a.difference(&set_b).copied()));
+            assert!(difference(&b, &a).eq(set_b.difference(&set_a).copied()));
+        }
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let a = [1, 1, 1, 2, 3, 3, 5, 5, 5, 5];
+        let b = [1, 3, 3, 3, 4, 5];
+        assert!(intersection(&a, &b).eq(&[1, 3, 5]));
+        assert!(union(&a, &b).eq(&[1, 2, 3, 4, 5]));
+        assert!(difference(&a, &b).eq(&[2]));
+        assert!(difference(&b, &a).eq(&[4]));
+    }
+
+    #[test]
+    fn test_k_way() {
+        let slices = [
+            sample(3000, 2, 1),
+            sample(2000, 3, 2),
+            sample(100, 60, 3),
+            (0..6000).collect(),
+        ];
+        let sets = slices
+            .iter()
+            .map(|slice| slice.iter().collect::<BTreeSet<_>>())
+            .collect::<Vec<_>>();
+        let expected = sets[1..].iter().fold(sets[0].clone(), |acc, set| {
+            acc.intersection(set).copied().collect()
+        });
+        assert!(intersection_all(slices.iter().map(Vec::as_slice)).eq(expected));
+        let expected = sets.iter().flatten().copied().collect::<BTreeSet<_>>();
+        assert!(union_all(slices.iter().map(Vec::as_slice)).eq(expected));
+
+        assert_eq!(intersection_all::<u64, _>([]).next(), None);
+        assert_eq!(union_all::<u64, _>([]).next(), None);
+        assert!(intersection_all([&[1, 2, 3][..]]).eq(&[1, 2, 3]));
+        assert!(intersection_all([&[1, 2][..], &[]]).eq(&[] as &[i32]));
+    }
+
+    #[test]
+    fn test_strings() {
+        let a = ["apple", "banana", "cherry", "date"];
+        let b = ["banana", "date", "fig"];
+        assert!(intersection(&a, &b).eq(&["banana", "date"]));
+        assert!(difference(&a, &b).eq(&["apple", "cherry"]));
+    }
+}
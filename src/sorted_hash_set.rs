@@ -0,0 +1,229 @@
+use crate::{interpolation_search::lower_bound_by_key, InterpolationFactor, InterpolationSearch};
+
+/// A compact set of fixed-width hashes, such as `u64` hashes or `[u8; 32]` digests, stored in a
+/// sorted boxed slice.
+///
+/// Good hashes are uniformly distributed, which is the ideal case for interpolation search: a
+/// lookup takes *O(log log N)* probes, a handful even for billions of hashes. Unlike a `HashSet`,
+/// the set has no per-element overhead beyond the hash itself, and it's built in one go from
+/// its hashes rather than element by element.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::SortedHashSet;
+///
+/// let set = SortedHashSet::from_unsorted(vec![0x9e37_79b9_7f4a_7c15_u64, 0x2545_f491_4f6c_dd1d]);
+/// assert!(set.contains(&0x2545_f491_4f6c_dd1d));
+/// assert_eq!(set.contains_batch(&[1, 0x9e37_79b9_7f4a_7c15]), [false, true]);
+///
+/// let digests = [[7_u8; 32], [1; 32], [4; 32]].into_iter().collect::<SortedHashSet<_>>();
+/// let stored = SortedHashSet::from_unsorted(vec![[4_u8; 32]]);
+/// assert_eq!(digests.difference(&stored).collect::<Vec<_>>(), [&[1; 32], &[7; 32]]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SortedHashSet<H = u64> {
+    hashes: Box<[H]>,
+}
+
+impl<H> SortedHashSet<H> {
+    /// Returns the number of hashes in the set.
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns `true` if the set has no hashes.
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Returns the hashes of the set, in sorted order.
+    pub fn as_slice(&self) -> &[H] {
+        &self.hashes
+    }
+
+    /// Consumes the set, returning its sorted hashes.
+    pub fn into_boxed_slice(self) -> Box<[H]> {
+        self.hashes
+    }
+}
+
+impl<H> SortedHashSet<H>
+where
+    H: Ord + InterpolationFactor,
+{
+    /// Creates a set from `hashes`, sorting them and removing duplicates.
+    pub fn from_unsorted(mut hashes: Vec<H>) -> Self {
+        hashes.sort_unstable();
+        hashes.dedup();
+        Self {
+            hashes: hashes.into_boxed_slice(),
+        }
+    }
+
+    /// Returns `true` if the set contains `hash`.
+    pub fn contains(&self, hash: &H) -> bool {
+        self.hashes.interpolation_search(hash).is_ok()
+    }
+
+    /// Returns for each of `hashes` whether the set contains it.
+    ///
+    /// If `hashes` is sorted, every search starts where the previous one ended, so the batch is
+    /// answered in a single pass over the set.
+    pub fn contains_batch(&self, hashes: &[H]) -> Vec<bool> {
+        if !hashes.is_sorted() {
+            return hashes.iter().map(|hash| self.contains(hash)).collect();
+        }
+        let mut start = 0;
+        hashes
+            .iter()
+            .map(|hash| {
+                start += lower_bound_by_key(&self.hashes[start..], hash, |x| x);
+                self.hashes.get(start) == Some(hash)
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the hashes of the set that are not in `other`, in sorted order.
+    ///
+    /// Every hash is searched for in the part of `other` after the previous one, so a small set
+    /// is diffed against a large one without a pass over the large one.
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a H> + 'a {
+        let mut rest = &other.hashes[..];
+        self.hashes
+            .iter()
+            .filter(move |hash| match rest.interpolation_search(hash) {
+                Ok(idx) => {
+                    rest = &rest[idx + 1..];
+                    false
+                }
+                Err(idx) => {
+                    rest = &rest[idx..];
+                    true
+                }
+            })
+    }
+}
+
+impl<H> Default for SortedHashSet<H> {
+    fn default() -> Self {
+        Self {
+            hashes: Box::default(),
+        }
+    }
+}
+
+impl<H> FromIterator<H> for SortedHashSet<H>
+where
+    H: Ord + InterpolationFactor,
+{
+    fn from_iter<I: IntoIterator<Item = H>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<H> From<Vec<H>> for SortedHashSet<H>
+where
+    H: Ord + InterpolationFactor,
+{
+    fn from(hashes: Vec<H>) -> Self {
+        Self::from_unsorted(hashes)
+    }
+}
+
+impl<'a, H> IntoIterator for &'a SortedHashSet<H> {
+    type Item = &'a H;
+    type IntoIter = std::slice::Iter<'a, H>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.hashes.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    fn digest(state: &mut u64) -> [u8; 32] {
+        let mut digest = [0; 32];
+        for chunk in digest.chunks_mut(8) {
+            chunk.copy_from_slice(&xorshift(state).to_le_bytes());
+        }
+        digest
+    }
+
+    #[test]
+    fn test_empty() {
+        let set = SortedHashSet::<u64>::default();
+        assert!(set.is_empty());
+        assert!(!set.contains(&1));
+        assert_eq!(set.contains_batch(&[1, 2]), [false, false]);
+        assert_eq!(set.difference(&set).count(), 0);
+    }
+
+    #[test]
+    fn test_u64_hashes() {
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        let hashes = (0..10_000)
+            .map(|_| xorshift(&mut state))
+            .collect::<Vec<_>>();
+        let set = SortedHashSet::from_unsorted(hashes[..5000].to_vec());
+        let expected = hashes[..5000].iter().collect::<HashSet<_>>();
+        assert_eq!(set.len(), 5000);
+        assert!(set.as_slice().is_sorted());
+
+        let found = set.contains_batch(&hashes);
+        for (hash, found) in hashes.iter().zip(found) {
+            assert_eq!(set.contains(hash), expected.contains(hash));
+            assert_eq!(found, expected.contains(hash));
+        }
+        let mut sorted = hashes.clone();
+        sorted.sort();
+        let found = set.contains_batch(&sorted);
+        assert_eq!(found.iter().filter(|&&found| found).count(), 5000);
+    }
+
+    #[test]
+    fn test_digests() {
+        let mut state = 0x9e37_79b9_7f4a_7c15;
+        let digests = (0..4000).map(|_| digest(&mut state)).collect::<Vec<_>>();
+        let set = digests[..3000]
+            .iter()
+            .copied()
+            .collect::<SortedHashSet<_>>();
+        let stored = digests[1000..]
+            .iter()
+            .copied()
+            .collect::<SortedHashSet<_>>();
+        for digest in &digests {
+            assert_eq!(set.contains(digest), digests[..3000].contains(digest));
+        }
+
+        let mut expected = digests[..1000].to_vec();
+        expected.sort();
+        assert!(set.difference(&stored).eq(expected.iter()));
+        assert_eq!(stored.difference(&set).count(), 1000);
+        assert_eq!(set.difference(&set).count(), 0);
+        assert_eq!(set.difference(&SortedHashSet::default()).count(), 3000);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let set = SortedHashSet::from(vec![[3_u8; 32], [1; 32], [3; 32]]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.contains_batch(&[[1; 32], [1; 32], [2; 32]]),
+            [true, true, false]
+        );
+        assert_eq!((&set).into_iter().count(), 2);
+        assert_eq!(set.into_boxed_slice().len(), 2);
+    }
+}
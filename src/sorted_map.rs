@@ -0,0 +1,521 @@
+//! A flat map backed by interpolation search, and the types of its entry API.
+
+use crate::{InterpolationFactor, InterpolationSearch};
+use std::{
+    borrow::Borrow,
+    ops::{Index, RangeBounds},
+};
+
+/// A flat map over a `Vec<(K, V)>` sorted by key, searched with interpolation search.
+///
+/// The entries are stored contiguously, which makes lookups and iteration cache friendly. Every
+/// key lookup goes through [`InterpolationSearch::interpolation_search_by_key`], so it takes
+/// *O(log log N)* comparisons on uniformly distributed keys. Insertions and removals shift the
+/// entries after their position, so the map suits read-heavy code best.
+///
+/// As with [`BTreeMap`](std::collections::BTreeMap), lookups accept any borrowed form of the key,
+/// e.g. a `&str` for `String` keys, as long as it's ordered and interpolated like the key.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::SortedMap;
+///
+/// let mut map = SortedMap::new();
+/// assert_eq!(map.insert(3, "c"), None);
+/// assert_eq!(map.insert(1, "a"), None);
+/// assert_eq!(map.insert(3, "C"), Some("c"));
+///
+/// assert_eq!(map.get(&3), Some(&"C"));
+/// *map.entry(2).or_insert("") = "b";
+/// assert_eq!(map.range(2..).collect::<Vec<_>>(), [(&2, &"b"), (&3, &"C")]);
+///
+/// assert_eq!(map.remove(&1), Some("a"));
+/// assert_eq!(map.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+/// A view into a single entry of a [`SortedMap`], which may either be vacant or occupied.
+///
+/// This is constructed by [`SortedMap::entry`].
+#[derive(Debug)]
+pub enum Entry<'a, K, V> {
+    /// An entry with a key that is in the map.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// An entry with a key that is not in the map.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+/// A view into an occupied entry of a [`SortedMap`].
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, K, V> {
+    entries: &'a mut Vec<(K, V)>,
+    idx: usize,
+}
+
+/// A view into a vacant entry of a [`SortedMap`].
+#[derive(Debug)]
+pub struct VacantEntry<'a, K, V> {
+    entries: &'a mut Vec<(K, V)>,
+    idx: usize,
+    key: K,
+}
+
+impl<K, V> SortedMap<K, V> {
+    /// Creates an empty map.
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries of the map, sorted by key.
+    pub fn as_slice(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    /// Consumes the map, returning its entries sorted by key.
+    pub fn into_vec(self) -> Vec<(K, V)> {
+        self.entries
+    }
+
+    /// Removes all entries from the map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the entries of the map, sorted by key.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries of the map with mutable references to the values,
+    /// sorted by key.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (&K, &mut V)> + ExactSizeIterator {
+        self.entries.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Returns an iterator over the keys of the map, in sorted order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values of the map, sorted by their keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.entries.iter().map(|(_, v)| v)
+    }
+
+    /// Retains only the entries for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+}
+
+impl<K, V> SortedMap<K, V>
+where
+    K: Ord + InterpolationFactor,
+{
+    /// Returns a reference to the value of `key`, if it's in the map.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        let idx = self.search(key).ok()?;
+        Some(&self.entries[idx].1)
+    }
+
+    /// Returns a mutable reference to the value of `key`, if it's in the map.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        let idx = self.search(key).ok()?;
+        Some(&mut self.entries[idx].1)
+    }
+
+    /// Returns the key-value pair of `key`, if it's in the map.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        let (k, v) = &self.entries[self.search(key).ok()?];
+        Some((k, v))
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Inserts `value` for `key`. If the map already contained the key, its value is replaced
+    /// and the old value is returned; the key itself is not updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(idx) => Some(std::mem::replace(&mut self.entries[idx].1, value)),
+            Err(idx) => {
+                self.entries.insert(idx, (key, value));
+                None
+            }
+        }
+    }
+
+    /// Removes `key` from the map, returning its value if it was in the map.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Removes `key` from the map, returning the stored key and value if it was in the map.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        let idx = self.search(key).ok()?;
+        Some(self.entries.remove(idx))
+    }
+
+    /// Returns the entry of `key` for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.search(&key) {
+            Ok(idx) => Entry::Occupied(OccupiedEntry {
+                entries: &mut self.entries,
+                idx,
+            }),
+            Err(idx) => Entry::Vacant(VacantEntry {
+                entries: &mut self.entries,
+                idx,
+                key,
+            }),
+        }
+    }
+
+    /// Returns an iterator over the entries with keys within `range`, sorted by key.
+    pub fn range<Q, R>(
+        &self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let range = self
+            .entries
+            .interpolation_range_by_key(range, |(k, _)| k.borrow());
+        self.entries[range].iter().map(|(k, v)| (k, v))
+    }
+
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + InterpolationFactor + ?Sized,
+    {
+        self.entries
+            .interpolation_search_by_key(key, |(k, _)| k.borrow())
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable reference
+    /// to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the default value if the entry is vacant, and returns a mutable reference to the
+    /// value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.entries[self.idx].0
+    }
+
+    /// Returns a reference to the value of the entry.
+    pub fn get(&self) -> &V {
+        &self.entries[self.idx].1
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries[self.idx].1
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries[self.idx].1
+    }
+
+    /// Replaces the value of the entry, returning the old value.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map, returning its value.
+    pub fn remove(self) -> V {
+        self.entries.remove(self.idx).1
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes the key of the entry back without inserting it.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts `value` for the key of the entry, and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.entries.insert(self.idx, (self.key, value));
+        &mut self.entries[self.idx].1
+    }
+}
+
+impl<K, V> Default for SortedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, Q, V> Index<&Q> for SortedMap<K, V>
+where
+    K: Ord + InterpolationFactor + Borrow<Q>,
+    Q: Ord + InterpolationFactor + ?Sized,
+{
+    type Output = V;
+
+    /// Returns a reference to the value of `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is not in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in the map")
+    }
+}
+
+impl<K, V> Extend<(K, V)> for SortedMap<K, V>
+where
+    K: Ord + InterpolationFactor,
+{
+    /// Appends the entries and sorts the map again. If a key occurs multiple times, the last
+    /// value wins, like repeated calls to [`SortedMap::insert`] would do.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let len = self.entries.len();
+        self.entries.extend(iter);
+        if self.entries.len() == len {
+            return;
+        }
+        // The stable sort keeps the entries of equal keys in insertion order. Reversed, the last
+        // one of them is the first, which is the one `dedup_by` keeps.
+        self.entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.entries.reverse();
+        self.entries.dedup_by(|(a, _), (b, _)| a == b);
+        self.entries.reverse();
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for SortedMap<K, V>
+where
+    K: Ord + InterpolationFactor,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> From<SortedMap<K, V>> for Vec<(K, V)> {
+    fn from(map: SortedMap<K, V>) -> Self {
+        map.entries
+    }
+}
+
+impl<K, V> IntoIterator for SortedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeMap, ops::Bound};
+
+    #[test]
+    fn test_empty() {
+        let mut map = SortedMap::<u32, u32>::default();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.get_mut(&1), None);
+        assert_eq!(map.remove(&1), None);
+        assert_eq!(map.range(..).count(), 0);
+    }
+
+    #[test]
+    fn test_against_btree_map() {
+        let mut map = SortedMap::new();
+        let mut expected = BTreeMap::new();
+        for n in 0..2000_u64 {
+            let key = n * 7919 % 1000;
+            assert_eq!(map.insert(key, n), expected.insert(key, n));
+            if n % 3 == 0 {
+                let key = n * 31 % 1000;
+                assert_eq!(map.remove(&key), expected.remove(&key));
+            }
+        }
+        assert!(map.iter().eq(expected.iter()));
+        for key in 0..1010 {
+            assert_eq!(map.get(&key), expected.get(&key));
+            assert_eq!(map.contains_key(&key), expected.contains_key(&key));
+        }
+        for (start, end) in [(0, 1000), (10, 20), (500, 501), (999, 2000), (30, 10)] {
+            let range = (Bound::Included(start), Bound::Excluded(end));
+            if start <= end {
+                assert!(map.range(range).eq(expected.range(range)));
+            } else {
+                assert_eq!(map.range(range).count(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map = SortedMap::new();
+        for word in ["b", "a", "c", "a", "b", "a"] {
+            *map.entry(word.to_string()).or_insert(0) += 1;
+        }
+        assert_eq!(map[&"a".to_string()], 3);
+        assert_eq!(map.get(&"b".to_string()), Some(&2));
+
+        map.entry("c".to_string()).and_modify(|n| *n *= 10);
+        map.entry("d".to_string())
+            .and_modify(|n| *n *= 10)
+            .or_default();
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [3, 2, 10, 0]);
+
+        match map.entry("b".to_string()) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), "b");
+                assert_eq!(entry.insert(5), 2);
+                assert_eq!(entry.remove(), 5);
+            }
+            Entry::Vacant(_) => unreachable!(),
+        }
+        match map.entry("e".to_string()) {
+            Entry::Vacant(entry) => assert_eq!(entry.into_key(), "e"),
+            Entry::Occupied(_) => unreachable!(),
+        }
+        assert_eq!(
+            map.keys().map(String::as_str).collect::<Vec<_>>(),
+            ["a", "c", "d"]
+        );
+    }
+
+    #[test]
+    fn test_borrowed_keys() {
+        let mut map = ["pear", "apple", "fig", "kiwi"]
+            .into_iter()
+            .map(|fruit| (fruit.to_string(), fruit.len()))
+            .collect::<SortedMap<_, _>>();
+        assert_eq!(map.get("fig"), Some(&3));
+        assert_eq!(map["apple"], 5);
+        assert!(map.contains_key("kiwi"));
+        assert!(!map.contains_key("plum"));
+        *map.get_mut("pear").unwrap() += 10;
+        assert_eq!(map.get_key_value("pear"), Some((&"pear".to_string(), &14)));
+        assert_eq!(
+            map.range::<str, _>((Bound::Included("b"), Bound::Excluded("l")))
+                .map(|(k, _)| k.as_str())
+                .collect::<Vec<_>>(),
+            ["fig", "kiwi"]
+        );
+        assert_eq!(map.remove("fig"), Some(3));
+        assert_eq!(map.remove_entry("kiwi"), Some(("kiwi".to_string(), 4)));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let map = [(3, 'c'), (1, 'a'), (3, 'C'), (2, 'b'), (1, 'A')]
+            .into_iter()
+            .collect::<SortedMap<_, _>>();
+        assert_eq!(map.as_slice(), [(1, 'A'), (2, 'b'), (3, 'C')]);
+
+        let mut map = map;
+        map.extend([(0, 'z'), (2, 'B')]);
+        map.retain(|k, _| *k != 1);
+        for (_, v) in map.iter_mut() {
+            *v = v.to_ascii_lowercase();
+        }
+        assert_eq!(Vec::from(map), [(0, 'z'), (2, 'b'), (3, 'c')]);
+    }
+}
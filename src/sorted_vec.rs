@@ -0,0 +1,267 @@
+use crate::{
+    interpolation_search::{lower_bound_by_key, upper_bound_by_key},
+    InterpolationFactor, InterpolationRangeSlice, InterpolationSearch,
+};
+use std::ops::{Deref, RangeBounds};
+
+/// A `Vec<T>` that is always sorted, searched with interpolation search.
+///
+/// Elements are kept in ascending order, duplicates included. Every lookup, insertion and removal
+/// finds its position with [`InterpolationSearch`], so it takes *O(log log N)* comparisons on
+/// uniformly distributed elements; insertion and removal still shift the elements after the
+/// position, like their `Vec` counterparts.
+///
+/// The vector dereferences to a sorted slice, so all the read-only slice methods, including
+/// [`InterpolationSearch::interpolation_search`], are available on it.
+///
+/// # Examples
+///
+/// ```
+/// use interpolation_search::SortedVec;
+///
+/// let mut vec = SortedVec::from_unsorted(vec![40, 10, 30]);
+/// assert_eq!(vec.insert(20), 1);
+/// assert_eq!(vec.as_slice(), [10, 20, 30, 40]);
+///
+/// assert!(vec.contains(&30));
+/// assert_eq!(vec.find(&40), Some(3));
+/// assert_eq!(vec.range(15..=30), [20, 30]);
+///
+/// vec.extend([25, 5]);
+/// assert_eq!(vec.remove(&10), Some(10));
+/// assert_eq!(vec.into_vec(), [5, 20, 25, 30, 40]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T> {
+    items: Vec<T>,
+}
+
+impl<T> SortedVec<T> {
+    /// Creates an empty vector.
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Returns the sorted elements of the vector.
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Consumes the vector, returning its sorted elements.
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Removes all elements from the vector.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Retains only the elements for which `f` returns `true`. Removing elements keeps the rest
+    /// sorted, so this is the same as [`Vec::retain`].
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.items.retain(f);
+    }
+}
+
+impl<T> SortedVec<T>
+where
+    T: Ord + InterpolationFactor,
+{
+    /// Creates a vector from the elements of `items`, sorting them.
+    pub fn from_unsorted(mut items: Vec<T>) -> Self {
+        items.sort();
+        Self { items }
+    }
+
+    /// Inserts `value` after all elements less than or equal to it, and returns its index.
+    pub fn insert(&mut self, value: T) -> usize {
+        let idx = upper_bound_by_key(&self.items, &value, |x| x);
+        self.items.insert(idx, value);
+        idx
+    }
+
+    /// Removes an element equal to `value` and returns it, if there's any. If there are multiple
+    /// matches, the first one is removed.
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let idx = self.find(value)?;
+        Some(self.items.remove(idx))
+    }
+
+    /// Returns `true` if the vector contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.items.interpolation_search(value).is_ok()
+    }
+
+    /// Returns the index of the first element equal to `value`, if there's any.
+    pub fn find(&self, value: &T) -> Option<usize> {
+        let idx = lower_bound_by_key(&self.items, value, |x| x);
+        self.items.get(idx).filter(|item| *item == value)?;
+        Some(idx)
+    }
+
+    /// Returns the sorted sub-slice of elements within `range`.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        self.items.interpolation_range_slice(range)
+    }
+}
+
+impl<T> Default for SortedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for SortedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> AsRef<[T]> for SortedVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T: Ord + InterpolationFactor> Extend<T> for SortedVec<T> {
+    /// Appends the elements and sorts the vector again. The stable sort detects the two sorted
+    /// runs, so an already sorted `iter` is merged in linear time.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let len = self.items.len();
+        self.items.extend(iter);
+        if self.items.len() > len {
+            self.items.sort();
+        }
+    }
+}
+
+impl<T: Ord + InterpolationFactor> FromIterator<T> for SortedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_unsorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord + InterpolationFactor> From<Vec<T>> for SortedVec<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self::from_unsorted(items)
+    }
+}
+
+impl<T> From<SortedVec<T>> for Vec<T> {
+    fn from(vec: SortedVec<T>) -> Self {
+        vec.items
+    }
+}
+
+impl<T> IntoIterator for SortedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Bound;
+
+    #[test]
+    fn test_empty() {
+        let mut vec = SortedVec::<u32>::default();
+        assert!(vec.is_empty());
+        assert!(!vec.contains(&1));
+        assert_eq!(vec.find(&1), None);
+        assert_eq!(vec.remove(&1), None);
+        assert!(vec.range(..).is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut vec = SortedVec::new();
+        let mut expected = Vec::new();
+        for n in 0..500_u64 {
+            let value = n * 7919 % 1000;
+            let idx = vec.insert(value);
+            assert_eq!(vec[idx], value);
+            expected.push(value);
+        }
+        expected.sort();
+        assert_eq!(vec.as_slice(), expected);
+        for n in (0..1000).step_by(3) {
+            assert_eq!(vec.contains(&n), expected.contains(&n));
+            assert_eq!(vec.remove(&n).is_some(), expected.contains(&n));
+        }
+        expected.retain(|n| n % 3 != 0);
+        assert_eq!(vec.into_vec(), expected);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut vec = SortedVec::from_unsorted(vec![3, 1, 2, 2, 2, 5]);
+        assert_eq!(vec.insert(2), 4);
+        assert_eq!(vec.find(&2), Some(1));
+        assert_eq!(vec.find(&4), None);
+        assert_eq!(vec.range(2..=2).len(), 4);
+        assert_eq!(vec.remove(&2), Some(2));
+        assert_eq!(vec.as_slice(), [1, 2, 2, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_range() {
+        let vec = (0..100).map(|n| n * 10).collect::<SortedVec<_>>();
+        assert_eq!(vec.range(15..45), [20, 30, 40]);
+        assert_eq!(vec.range(20..40), [20, 30]);
+        assert_eq!(vec.range(20..=40), [20, 30, 40]);
+        assert_eq!(
+            vec.range((Bound::Excluded(20), Bound::Included(40))),
+            [30, 40]
+        );
+        assert_eq!(vec.range(..25), [0, 10, 20]);
+        assert_eq!(vec.range(975..), [980, 990]);
+        assert!(vec
+            .range((Bound::Included(40), Bound::Excluded(20)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_retain_and_extend() {
+        let mut vec = SortedVec::from(vec![5, 1, 4]);
+        vec.extend([3, 2, 6]);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+        vec.retain(|n| n % 2 == 0);
+        assert_eq!(vec.as_slice(), [2, 4, 6]);
+        vec.extend(Vec::new());
+        assert_eq!(Vec::from(vec), [2, 4, 6]);
+    }
+
+    #[test]
+    fn test_strings() {
+        let mut vec = ["pear", "apple", "fig"]
+            .into_iter()
+            .map(String::from)
+            .collect::<SortedVec<_>>();
+        vec.insert("banana".to_string());
+        assert_eq!(vec.find(&"fig".to_string()), Some(2));
+        assert_eq!(
+            (&vec).into_iter().map(String::as_str).collect::<Vec<_>>(),
+            ["apple", "banana", "fig", "pear"]
+        );
+    }
+}